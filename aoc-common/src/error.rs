@@ -0,0 +1,168 @@
+//! Errors for puzzle inputs that can't be parsed, pointing at exactly where the input went wrong.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    /// The line the error is on, starting from 1.
+    pub line: usize,
+    /// The column the offending text starts at, in characters and starting from 1.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new<S: Into<String>>(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: &str,
+        message: S,
+    ) -> ParseError {
+        ParseError {
+            day,
+            line,
+            column,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about the input as a whole, such as it ending too early, which is reported at the
+    /// end of the input.
+    pub fn at_end<S: Into<String>>(day: u8, input: &str, message: S) -> ParseError {
+        let line = input.trim_end().lines().count().max(1);
+        let column = input
+            .trim_end()
+            .lines()
+            .last()
+            .map(|last| last.chars().count() + 1)
+            .unwrap_or(1);
+        ParseError::new(day, line, column, "", message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " (found `{}`)", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of puzzle input, remembering where it came from so errors can point back at it.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// The line number, starting from 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(day: u8, number: usize, text: &'a str) -> Line<'a> {
+        Line { day, number, text }
+    }
+
+    /// An error about `span`, which must be a slice of this line's text.
+    pub fn error<S: Into<String>>(&self, span: &str, message: S) -> ParseError {
+        ParseError::new(self.day, self.number, self.column_of(span), span, message)
+    }
+
+    /// An error about the whole line.
+    pub fn invalid<S: Into<String>>(&self, message: S) -> ParseError {
+        self.error(self.text, message)
+    }
+
+    /// Parses `span`, a slice of this line's text, reporting `expected` if it isn't valid.
+    pub fn parse<T: FromStr>(&self, span: &str, expected: &str) -> Result<T, ParseError> {
+        span.parse()
+            .map_err(|_| self.error(span, format!("expected {}", expected)))
+    }
+
+    /// Splits the line's text at the first `delimiter`, which must be present.
+    pub fn split_once(
+        &self,
+        span: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        span.split_once(delimiter)
+            .ok_or_else(|| self.error(span, format!("expected `{}`", delimiter)))
+    }
+
+    fn column_of(&self, span: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(0);
+        self.text[..offset].chars().count() + 1
+    }
+}
+
+/// The non-blank lines of `input`, numbered by their position in the whole input.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(move |(index, text)| Line::new(day, index + 1, text))
+}
+
+/// The only non-blank line of `input`, for puzzles whose input is a single `expected` value.
+pub fn single_line<'a>(day: u8, input: &'a str, expected: &str) -> Result<Line<'a>, ParseError> {
+    let mut lines = lines(day, input);
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::at_end(day, input, format!("expected {}", expected)))?;
+    match lines.next() {
+        Some(extra) => Err(extra.invalid(format!("expected only {} on one line", expected))),
+        None => Ok(line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lines, single_line, ParseError};
+
+    #[test]
+    fn errors_point_at_spans() {
+        let input = "1 2\n\n3 x4";
+        let line = lines(7, input).nth(1).unwrap();
+        assert_eq!(line.number, 3);
+
+        let token = line.text.split_whitespace().nth(1).unwrap();
+        let err = line.parse::<u32>(token, "a number").unwrap_err();
+        assert_eq!(err, ParseError::new(7, 3, 3, "x4", "expected a number"));
+        assert_eq!(
+            err.to_string(),
+            "Day 7, line 3, column 3: expected a number (found `x4`)"
+        );
+    }
+
+    #[test]
+    fn end_of_input() {
+        let err = ParseError::at_end(1, "ab\ncde\n", "too short");
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn single_lines() {
+        assert_eq!(single_line(1, "\n 42 \n\n", "a number").unwrap().number, 2);
+        assert_eq!(single_line(1, "", "a number").unwrap_err().line, 1);
+        assert_eq!(single_line(1, "1\n2", "a number").unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,345 @@
+//! Points, vectors, rectangles and directions on a 2D plane where `y` grows downwards.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The difference between two points.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i64 {
+        (self - other).manhattan_length()
+    }
+
+    pub fn chebyshev_distance(self, other: Point) -> i64 {
+        (self - other).chebyshev_length()
+    }
+
+    /// The point as a `(x, y)` grid position, or `None` if either coordinate is negative.
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        if self.x < 0 || self.y < 0 {
+            None
+        } else {
+            Some((self.x as usize, self.y as usize))
+        }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Point {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+/// Points are ordered top to bottom, then left to right, the order text is read in.
+impl Ord for Point {
+    fn cmp(&self, other: &Point) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Vector {
+    pub const fn new(x: i64, y: i64) -> Vector {
+        Vector { x, y }
+    }
+
+    pub fn manhattan_length(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev_length(self) -> i64 {
+        self.x.abs().max(self.y.abs())
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scale: i64) -> Vector {
+        Vector::new(self.x * scale, self.y * scale)
+    }
+}
+
+/// An axis-aligned rectangle, including both its `min` and `max` corners.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    /// Creates the rectangle spanning two opposite corners.
+    pub fn new(a: Point, b: Point) -> Rect {
+        Rect {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// Creates a rectangle from its top left corner and size, or `None` if it would be empty.
+    pub fn with_size(corner: Point, width: i64, height: i64) -> Option<Rect> {
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        Some(Rect {
+            min: corner,
+            max: corner + Vector::new(width - 1, height - 1),
+        })
+    }
+
+    /// The smallest rectangle containing every point, or `None` if there are no points.
+    pub fn bounding<I>(points: I) -> Option<Rect>
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        points.into_iter().fold(None, |bounds, point| match bounds {
+            None => Some(Rect::new(point, point)),
+            Some(bounds) => Some(bounds.including(point)),
+        })
+    }
+
+    /// Grows the rectangle to contain `point`.
+    pub fn including(self, point: Point) -> Rect {
+        Rect {
+            min: Point::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            max: Point::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        }
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// The area covered by both rectangles, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+
+        if min.x <= max.x && min.y <= max.y {
+            Some(Rect { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// The smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &Rect) -> Rect {
+        self.including(other.min).including(other.max)
+    }
+
+    /// Every point in the rectangle, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Rect { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().reverse()
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// The vector for a single step in this direction.
+    pub fn offset(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+            Direction::Right => Vector::new(1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Point, Rect, Vector};
+
+    #[test]
+    fn distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 6);
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a - Vector::new(1, 1), Point::new(0, -3));
+    }
+
+    #[test]
+    fn rect_operations() {
+        let a = Rect::with_size(Point::new(1, 3), 4, 4).unwrap();
+        let b = Rect::with_size(Point::new(3, 1), 4, 4).unwrap();
+        let c = Rect::with_size(Point::new(5, 5), 2, 2).unwrap();
+
+        assert_eq!(a.area(), 16);
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::new(Point::new(3, 3), Point::new(4, 4)))
+        );
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.union(&c), Rect::new(Point::new(1, 3), Point::new(6, 6)));
+        assert_eq!(a.points().count(), 16);
+        assert!(a.contains(Point::new(4, 6)));
+        assert!(!a.contains(Point::new(5, 6)));
+        assert_eq!(Rect::with_size(Point::ORIGIN, 0, 3), None);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = vec![Point::new(2, 5), Point::new(-1, 3), Point::new(4, 4)];
+        assert_eq!(
+            Rect::bounding(points),
+            Some(Rect::new(Point::new(-1, 3), Point::new(4, 5)))
+        );
+        assert_eq!(Rect::bounding(Vec::new()), None);
+    }
+
+    #[test]
+    fn turning() {
+        for direction in Direction::ALL.iter().cloned() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.reverse());
+            assert_eq!(
+                direction.offset() + direction.reverse().offset(),
+                Vector::new(0, 0)
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn reading_order() {
+        let mut points = vec![Point::new(3, 1), Point::new(0, 2), Point::new(1, 1)];
+        points.sort();
+        assert_eq!(
+            points,
+            vec![Point::new(1, 1), Point::new(3, 1), Point::new(0, 2)]
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Pictures of puzzles, written out as PPM, PNG or SVG files for inspecting while debugging.
+
+use crate::geometry::{Point, Rect};
+use crate::grid::Grid;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// The colour `amount` of the way from `self` to `other`, where `amount` is between 0 and 1.
+    pub fn mix(self, other: Rgb, amount: f64) -> Rgb {
+        let amount = amount.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| {
+            (f64::from(from) * (1.0 - amount) + f64::from(to) * amount).round() as u8
+        };
+        Rgb::new(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+        )
+    }
+
+    /// A bright colour picked by `index`, so that neighbouring indices get clearly different
+    /// colours.
+    pub fn distinct(index: usize) -> Rgb {
+        // Stepping around the hue circle by the golden angle never lands on the same hue twice.
+        let hue = (index as f64 * 137.508) % 360.0;
+        let sector = hue / 60.0;
+        let rising = (sector % 1.0 * 200.0) as u8 + 55;
+        let falling = 255 - rising + 55;
+        match sector as u32 {
+            0 => Rgb::new(255, rising, 55),
+            1 => Rgb::new(falling, 255, 55),
+            2 => Rgb::new(55, 255, rising),
+            3 => Rgb::new(55, falling, 255),
+            4 => Rgb::new(rising, 55, 255),
+            _ => Rgb::new(255, 55, falling),
+        }
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// The file formats an image can be saved as.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// The format named by `path`'s extension.
+    pub fn from_path(path: &Path) -> Option<ImageFormat> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// A picture made of one pixel per grid cell or point.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// The most pixels an image can have, which keeps a badly spread out puzzle from using up all
+    /// of the memory.
+    pub const MAX_PIXELS: usize = 1 << 24;
+
+    /// Creates an image with every pixel set to `background`, or an error if it would be too big.
+    pub fn new(width: usize, height: usize, background: Rgb) -> Result<Image, String> {
+        match width.checked_mul(height) {
+            Some(pixels) if pixels <= Image::MAX_PIXELS => Ok(Image {
+                width,
+                height,
+                pixels: vec![background; pixels],
+            }),
+            _ => Err(format!(
+                "A {}x{} image is larger than the limit of {} pixels.",
+                width,
+                height,
+                Image::MAX_PIXELS
+            )),
+        }
+    }
+
+    /// Draws a grid with one pixel per cell, coloured by `colour`.
+    pub fn from_grid<T, F>(grid: &Grid<T>, mut colour: F) -> Result<Image, String>
+    where
+        F: FnMut(&T) -> Rgb,
+    {
+        let mut image = Image::new(grid.width(), grid.height(), Rgb::BLACK)?;
+        for (pixel, cell) in image.pixels.iter_mut().zip(grid.cells()) {
+            *pixel = colour(cell);
+        }
+        Ok(image)
+    }
+
+    /// Draws a set of points on a `background` just big enough to hold them, returning the image
+    /// along with the area it covers.
+    pub fn from_points<I, F>(
+        points: I,
+        background: Rgb,
+        mut colour: F,
+    ) -> Result<(Image, Rect), String>
+    where
+        I: IntoIterator<Item = Point>,
+        I::IntoIter: Clone,
+        F: FnMut(Point) -> Rgb,
+    {
+        let points = points.into_iter();
+        let bounds = Rect::bounding(points.clone()).ok_or("There are no points to draw.")?;
+        let mut image = Image::new(
+            usize::try_from(bounds.width()).map_err(|err| err.to_string())?,
+            usize::try_from(bounds.height()).map_err(|err| err.to_string())?,
+            background,
+        )?;
+        for point in points {
+            let offset = point - bounds.min;
+            image.set(Point::new(offset.x, offset.y), colour(point));
+        }
+        Ok((image, bounds))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The colour of the pixel at `point`, or `None` if it is outside of the image.
+    pub fn get(&self, point: Point) -> Option<Rgb> {
+        self.offset(point).map(|offset| self.pixels[offset])
+    }
+
+    /// Colours the pixel at `point`, doing nothing if it is outside of the image.
+    pub fn set(&mut self, point: Point, colour: Rgb) {
+        if let Some(offset) = self.offset(point) {
+            self.pixels[offset] = colour;
+        }
+    }
+
+    /// Colours the one pixel wide border of `rect`.
+    pub fn outline(&mut self, rect: Rect, colour: Rgb) {
+        for point in rect.points() {
+            let on_edge = point.x == rect.min.x
+                || point.x == rect.max.x
+                || point.y == rect.min.y
+                || point.y == rect.max.y;
+            if on_edge {
+                self.set(point, colour);
+            }
+        }
+    }
+
+    /// The image with every pixel blown up into a `factor` by `factor` square, for pictures too
+    /// small to make out.
+    pub fn scaled(&self, factor: usize) -> Result<Image, String> {
+        let width = self.width.saturating_mul(factor);
+        let height = self.height.saturating_mul(factor);
+        let mut image = Image::new(width, height, Rgb::BLACK)?;
+        for (index, pixel) in image.pixels.iter_mut().enumerate() {
+            let (x, y) = (index % width / factor, index / width / factor);
+            *pixel = self.pixels[y * self.width + x];
+        }
+        Ok(image)
+    }
+
+    /// Saves the image in the format named by the file's extension.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Can't tell the image format of {}, expected a .ppm, .png or .svg file.",
+                    path.display()
+                ),
+            )
+        })?;
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write(format, &mut out)?;
+        out.flush()
+    }
+
+    pub fn write<W: Write>(&self, format: ImageFormat, out: W) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(out),
+            ImageFormat::Png => self.write_png(out),
+            ImageFormat::Svg => self.write_svg(out),
+        }
+    }
+
+    /// Writes the image as a binary PPM, which almost every image tool can read.
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())
+    }
+
+    pub fn write_png<W: Write>(&self, out: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.bytes())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    /// Writes the image as an SVG, with one rectangle for each run of same coloured pixels in a
+    /// row, so it stays sharp however far it is zoomed in.
+    pub fn write_svg<W: Write>(&self, mut out: W) -> io::Result<()> {
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}" shape-rendering="crispEdges">"#,
+            self.width, self.height
+        )?;
+        for (y, row) in self.pixels.chunks(self.width.max(1)).enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{}" height="1" fill="{}"/>"#,
+                    x,
+                    y,
+                    run.len(),
+                    run[0].hex()
+                )?;
+                x += run.len();
+            }
+        }
+        writeln!(out, "</svg>")
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|pixel| [pixel.r, pixel.g, pixel.b])
+            .collect()
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let (x, y) = point.to_position()?;
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Image, ImageFormat, Rgb};
+    use crate::geometry::{Point, Rect};
+    use std::path::Path;
+
+    fn example() -> Image {
+        let mut image = Image::new(3, 2, Rgb::BLACK).unwrap();
+        image.set(Point::new(2, 0), Rgb::new(1, 2, 3));
+        image.set(Point::new(0, 1), Rgb::WHITE);
+        image.set(Point::new(5, 5), Rgb::WHITE);
+        image
+    }
+
+    #[test]
+    fn ppm() {
+        let mut ppm = Vec::new();
+        example().write_ppm(&mut ppm).unwrap();
+        let mut expected = b"P6\n3 2\n255\n".to_vec();
+        expected.extend([0, 0, 0, 0, 0, 0, 1, 2, 3, 255, 255, 255, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(ppm, expected);
+    }
+
+    #[test]
+    fn svg_merges_runs() {
+        let mut svg = Vec::new();
+        example().write_svg(&mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert_eq!(svg.matches("<rect").count(), 4);
+        assert!(svg.contains(r##"<rect x="0" y="0" width="2" height="1" fill="#000000"/>"##));
+        assert!(svg.contains(r##"<rect x="1" y="1" width="2" height="1" fill="#000000"/>"##));
+    }
+
+    #[test]
+    fn png_round_trip() {
+        let mut png = Vec::new();
+        example().write_png(&mut png).unwrap();
+        let mut reader = png::Decoder::new(&png[..]).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(&pixels[6..12], &[1, 2, 3, 255, 255, 255]);
+    }
+
+    #[test]
+    fn points_and_limits() {
+        let points = vec![Point::new(-2, 5), Point::new(1, 7)];
+        let (image, bounds) = Image::from_points(points, Rgb::BLACK, |_| Rgb::WHITE).unwrap();
+        assert_eq!(bounds, Rect::new(Point::new(-2, 5), Point::new(1, 7)));
+        assert_eq!((image.width(), image.height()), (4, 3));
+        assert_eq!(image.get(Point::new(3, 2)), Some(Rgb::WHITE));
+        assert_eq!(image.get(Point::new(1, 1)), Some(Rgb::BLACK));
+
+        assert!(Image::new(1 << 13, 1 << 13, Rgb::BLACK).is_err());
+        assert!(Image::new(usize::MAX, 2, Rgb::BLACK).is_err());
+        assert_eq!(
+            ImageFormat::from_path(Path::new("a.PNG")),
+            Some(ImageFormat::Png)
+        );
+        assert_eq!(ImageFormat::from_path(Path::new("a.jpg")), None);
+    }
+
+    #[test]
+    fn scaling() {
+        let image = example().scaled(2).unwrap();
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.get(Point::new(5, 1)), Some(Rgb::new(1, 2, 3)));
+        assert_eq!(image.get(Point::new(1, 3)), Some(Rgb::WHITE));
+        assert_eq!(image.get(Point::new(2, 3)), Some(Rgb::BLACK));
+        assert!(example().scaled(usize::MAX).is_err());
+    }
+}
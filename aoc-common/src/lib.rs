@@ -0,0 +1,85 @@
+//! Code shared between the solutions for each day.
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt::Display;
+
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod rng;
+
+pub use crate::error::{Line, ParseError};
+pub use crate::geometry::{Direction, Point, Rect, Vector};
+pub use crate::grid::Grid;
+pub use crate::image::{Image, Rgb};
+pub use crate::rng::Rng;
+
+/// A day's puzzle: parsing the input into a typed puzzle, and solving both parts of it.
+pub trait Solution {
+    /// The day in December the puzzle was released.
+    const DAY: u8;
+
+    /// The puzzle input bundled with the day's crate, used when no other input is given.
+    const INPUT: &'static str;
+
+    /// The parsed puzzle input.
+    type Puzzle;
+
+    /// Parameters the puzzle statement fixes outside of the input, such as how many workers there
+    /// are. The default is the puzzle as stated.
+    type Config: Default + DeserializeOwned;
+
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Puzzle, ParseError>;
+
+    fn part_one(
+        puzzle: &Self::Puzzle,
+        config: &Self::Config,
+    ) -> Result<Self::PartOne, Box<dyn Error>>;
+
+    fn part_two(
+        puzzle: &Self::Puzzle,
+        config: &Self::Config,
+    ) -> Result<Self::PartTwo, Box<dyn Error>>;
+}
+
+/// A day that can make up inputs of its own, for finding out how its solution scales.
+pub trait Generator: Solution {
+    /// Makes up a valid input of roughly `size` items, such as lines, claims or tree nodes. Every
+    /// random choice comes from `rng`, so the same seed always gives the same input.
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+/// A day whose puzzle can be drawn, for inspecting it while debugging.
+pub trait Render: Solution {
+    fn render(puzzle: &Self::Puzzle, config: &Self::Config) -> Result<Image, Box<dyn Error>>;
+}
+
+/// A day whose puzzle is a simulation that can be played back step by step.
+pub trait Animate: Solution {
+    /// Starts the simulation from the puzzle's initial state.
+    fn animate(
+        puzzle: &Self::Puzzle,
+        config: &Self::Config,
+    ) -> Result<Box<dyn Simulation>, Box<dyn Error>>;
+}
+
+/// A running simulation, such as the lights in the sky or the carts on their tracks.
+pub trait Simulation {
+    /// Moves the simulation on by one step, or returns `false` without changing anything once
+    /// there is nothing left to happen.
+    fn step(&mut self) -> Result<bool, Box<dyn Error>>;
+
+    /// Draws the current state as lines of text that fit in `width` columns and `height` rows.
+    fn frame(&self, width: usize, height: usize) -> String;
+}
+
+/// The config for puzzles without any parameters.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoConfig {}
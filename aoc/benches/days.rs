@@ -0,0 +1,42 @@
+//! Benchmarks parsing and solving both parts of every day's bundled input.
+//!
+//! Run with `cargo bench -p aoc`, or `cargo bench -p aoc -- day06` to only benchmark one day.
+
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    let puzzle = S::parse(S::INPUT).unwrap();
+    let config = S::Config::default();
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(S::INPUT))));
+    group.bench_function("part1", |b| {
+        b.iter(|| S::part_one(black_box(&puzzle), &config))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| S::part_two(black_box(&puzzle), &config))
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = days;
+    config = Criterion::default().sample_size(10);
+    targets =
+        bench_day::<day01::Day01>,
+        bench_day::<day02::Day02>,
+        bench_day::<day03::Day03>,
+        bench_day::<day04::Day04>,
+        bench_day::<day05::Day05>,
+        bench_day::<day06::Day06>,
+        bench_day::<day07::Day07>,
+        bench_day::<day08::Day08>,
+        bench_day::<day09::Day09>,
+        bench_day::<day10::Day10>,
+        bench_day::<day11::Day11>,
+        bench_day::<day12::Day12>,
+        bench_day::<day13::Day13>,
+        bench_day::<day14::Day14>,
+}
+criterion_main!(days);
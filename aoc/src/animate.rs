@@ -0,0 +1,394 @@
+//! Plays a day's simulation back in the terminal, one step at a time.
+
+use aoc_common::Simulation;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::error::Error;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// Starts a day's simulation over from the beginning.
+pub type Start<'a> = dyn Fn() -> Result<Box<dyn Simulation>, Box<dyn Error>> + 'a;
+
+const CONTROLS: &str = "space pause, → step, +/- speed, digits and enter jump, r restart, q quit";
+
+/// The slowest and fastest the simulation can be played, in steps per second.
+const MIN_SPEED: f64 = 0.25;
+const MAX_SPEED: f64 = 100_000.0;
+
+/// How long to wait for a key while nothing is playing.
+const IDLE: Duration = Duration::from_millis(250);
+/// How long each frame is shown for, at most.
+const FRAME: Duration = Duration::from_millis(1000 / 60);
+
+/// Where the player is up to and how it is playing, kept apart from the terminal so the controls
+/// can be tested.
+#[derive(Debug, PartialEq)]
+struct Player {
+    tick: u64,
+    /// How many steps to play a second.
+    speed: f64,
+    paused: bool,
+    /// Whether the simulation has nothing left to do, or failed.
+    finished: bool,
+    error: Option<String>,
+    /// The digits typed so far of a tick to jump to.
+    jump: String,
+    /// The tick being jumped to, while the steps up to it are taken.
+    target: Option<u64>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    Step,
+    Jump(u64),
+    Quit,
+}
+
+impl Player {
+    fn new(speed: f64, paused: bool) -> Player {
+        Player {
+            tick: 0,
+            speed: speed.clamp(MIN_SPEED, MAX_SPEED),
+            paused,
+            finished: false,
+            error: None,
+            jump: String::new(),
+            target: None,
+        }
+    }
+
+    /// Updates the player for a key press, returning anything the simulation needs to do about it.
+    fn press(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Some(Action::Quit)
+            }
+            KeyCode::Char('q') => return Some(Action::Quit),
+            KeyCode::Esc if !self.jump.is_empty() => self.jump.clear(),
+            KeyCode::Esc if self.target.is_some() => self.target = None,
+            KeyCode::Esc => return Some(Action::Quit),
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Right | KeyCode::Char('.') => {
+                self.paused = true;
+                return Some(Action::Step);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.speed = (self.speed * 2.0).min(MAX_SPEED)
+            }
+            KeyCode::Char('-') => self.speed = (self.speed / 2.0).max(MIN_SPEED),
+            KeyCode::Char('r') => return Some(Action::Jump(0)),
+            KeyCode::Char(digit @ '0'..='9') => self.jump.push(digit),
+            KeyCode::Backspace => {
+                self.jump.pop();
+            }
+            KeyCode::Enter if !self.jump.is_empty() => {
+                let target = self.jump.parse().unwrap_or(u64::MAX);
+                self.jump.clear();
+                return Some(Action::Jump(target));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn status(&self) -> String {
+        let state = if let Some(error) = &self.error {
+            format!("Error: {}", error)
+        } else if !self.jump.is_empty() {
+            format!("jump to tick {}_", self.jump)
+        } else if let Some(target) = self.target {
+            format!("jumping to tick {}, esc to stop", target)
+        } else if self.finished {
+            "finished".to_string()
+        } else if self.paused {
+            "paused".to_string()
+        } else {
+            "playing".to_string()
+        };
+        format!(
+            "tick {} | {} steps/s | {} | {}",
+            self.tick, self.speed, state, CONTROLS
+        )
+    }
+}
+
+/// The simulation along with how far it has got.
+struct Playback<'a> {
+    start: &'a Start<'a>,
+    simulation: Box<dyn Simulation>,
+    player: Player,
+}
+
+impl<'a> Playback<'a> {
+    fn new(start: &'a Start<'a>, player: Player) -> Result<Playback<'a>, Box<dyn Error>> {
+        Ok(Playback {
+            start,
+            simulation: start()?,
+            player,
+        })
+    }
+
+    fn step(&mut self) {
+        if self.player.finished {
+            return;
+        }
+        match self.simulation.step() {
+            Ok(true) => self.player.tick += 1,
+            Ok(false) => self.player.finished = true,
+            Err(err) => {
+                self.player.error = Some(err.to_string());
+                self.player.finished = true;
+            }
+        }
+    }
+
+    /// Starts jumping to `tick`, starting over first if it has already passed. The steps up to it
+    /// are taken by [`Playback::catch_up`].
+    fn jump(&mut self, tick: u64) -> Result<(), Box<dyn Error>> {
+        if tick < self.player.tick || self.player.error.is_some() {
+            self.simulation = (self.start)()?;
+            self.player.tick = 0;
+            self.player.finished = false;
+            self.player.error = None;
+        }
+        self.player.target = Some(tick);
+        Ok(())
+    }
+
+    /// Takes steps towards the tick being jumped to, without drawing anything, until it's reached
+    /// or `until` passes. A long jump is taken a frame at a time so that keys are still read.
+    fn catch_up(&mut self, until: Instant) {
+        while let Some(target) = self.player.target {
+            if self.player.tick >= target || self.player.finished {
+                self.player.target = None;
+            } else if Instant::now() >= until {
+                break;
+            } else {
+                self.step();
+            }
+        }
+    }
+}
+
+/// Takes over the terminal, putting it back how it was when dropped, even after an error.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Screen)
+    }
+
+    fn draw(&self, playback: &Playback) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let (width, height) = (usize::from(columns), usize::from(rows));
+        let frame = playback.simulation.frame(width, height.saturating_sub(1));
+
+        let mut out = io::stdout().lock();
+        let lines = frame.lines().take(height.saturating_sub(1));
+        for (row, line) in lines.enumerate() {
+            let line = line.chars().take(width).collect::<String>();
+            queue!(
+                out,
+                MoveTo(0, row as u16),
+                Print(line),
+                Clear(ClearType::UntilNewLine)
+            )?;
+        }
+        let status = playback
+            .player
+            .status()
+            .chars()
+            .take(width)
+            .collect::<String>();
+        queue!(
+            out,
+            Clear(ClearType::FromCursorDown),
+            MoveTo(0, rows.saturating_sub(1)),
+            Print(status),
+            Clear(ClearType::UntilNewLine)
+        )?;
+        out.flush()
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Plays the simulation made by `start` in the terminal until the viewer quits, starting from
+/// tick `from` and playing `speed` steps a second.
+pub fn play(start: &Start, from: u64, speed: f64, paused: bool) -> Result<(), Box<dyn Error>> {
+    let mut playback = Playback::new(start, Player::new(speed, paused))?;
+    playback.jump(from)?;
+
+    let screen = Screen::enter()?;
+    let mut next_step = Instant::now();
+    loop {
+        screen.draw(&playback)?;
+
+        let jumping = playback.player.target.is_some();
+        let playing = !playback.player.paused && !playback.player.finished;
+        let timeout = if jumping {
+            Duration::ZERO
+        } else if playing {
+            next_step.saturating_duration_since(Instant::now())
+        } else {
+            IDLE
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Release {
+                    continue;
+                }
+                match playback.player.press(key) {
+                    Some(Action::Quit) => return Ok(()),
+                    Some(Action::Step) => playback.step(),
+                    Some(Action::Jump(tick)) => playback.jump(tick)?,
+                    None => {}
+                }
+                next_step = Instant::now();
+            }
+            continue;
+        }
+        if jumping {
+            playback.catch_up(Instant::now() + FRAME);
+            next_step = Instant::now();
+            continue;
+        }
+        if !playing {
+            continue;
+        }
+
+        // Faster than the screen can keep up with, several steps are taken between frames.
+        let interval = Duration::from_secs_f64(1.0 / playback.player.speed);
+        let frame_end = Instant::now() + FRAME;
+        while next_step <= Instant::now() && Instant::now() < frame_end && !playback.player.finished
+        {
+            playback.step();
+            next_step += interval;
+        }
+        if next_step < Instant::now() {
+            next_step = Instant::now() + interval;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, Playback, Player, Start};
+    use aoc_common::Simulation;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::error::Error;
+    use std::time::{Duration, Instant};
+
+    /// Counts up to three.
+    struct Counter(u64);
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> Result<bool, Box<dyn Error>> {
+            if self.0 == 3 {
+                return Ok(false);
+            }
+            self.0 += 1;
+            Ok(true)
+        }
+
+        fn frame(&self, _: usize, _: usize) -> String {
+            self.0.to_string()
+        }
+    }
+
+    /// Never stops.
+    struct Forever;
+
+    impl Simulation for Forever {
+        fn step(&mut self) -> Result<bool, Box<dyn Error>> {
+            Ok(true)
+        }
+
+        fn frame(&self, _: usize, _: usize) -> String {
+            String::new()
+        }
+    }
+
+    fn jump(playback: &mut Playback, tick: u64) {
+        playback.jump(tick).unwrap();
+        playback.catch_up(Instant::now() + Duration::from_secs(60));
+    }
+
+    fn press(player: &mut Player, code: KeyCode) -> Option<Action> {
+        player.press(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn controls() {
+        let mut player = Player::new(10.0, false);
+        assert_eq!(press(&mut player, KeyCode::Char(' ')), None);
+        assert!(player.paused);
+        assert_eq!(press(&mut player, KeyCode::Char('+')), None);
+        assert_eq!(player.speed, 20.0);
+        assert_eq!(press(&mut player, KeyCode::Right), Some(Action::Step));
+
+        for digit in "125".chars() {
+            press(&mut player, KeyCode::Char(digit));
+        }
+        press(&mut player, KeyCode::Backspace);
+        assert!(player.status().contains("jump to tick 12_"));
+        assert_eq!(press(&mut player, KeyCode::Enter), Some(Action::Jump(12)));
+        assert_eq!(press(&mut player, KeyCode::Enter), None);
+        assert_eq!(press(&mut player, KeyCode::Char('q')), Some(Action::Quit));
+    }
+
+    #[test]
+    fn jumping() {
+        let start: &Start = &|| Ok(Box::new(Counter(0)));
+        let mut playback = Playback::new(start, Player::new(1.0, true)).unwrap();
+        jump(&mut playback, 2);
+        assert_eq!(
+            (playback.player.tick, playback.simulation.frame(0, 0)),
+            (2, "2".into())
+        );
+
+        jump(&mut playback, 10);
+        assert_eq!(playback.player.tick, 3);
+        assert!(playback.player.finished);
+
+        jump(&mut playback, 1);
+        assert_eq!(
+            (playback.player.tick, playback.simulation.frame(0, 0)),
+            (1, "1".into())
+        );
+        assert!(!playback.player.finished);
+        assert_eq!(playback.player.target, None);
+    }
+
+    #[test]
+    fn long_jumps_can_be_stopped() {
+        let start: &Start = &|| Ok(Box::new(Forever));
+        let mut playback = Playback::new(start, Player::new(1.0, true)).unwrap();
+        playback.jump(u64::MAX).unwrap();
+        playback.catch_up(Instant::now() + Duration::from_millis(10));
+        assert_eq!(playback.player.target, Some(u64::MAX));
+        assert!(playback.player.tick > 0);
+        assert!(playback.player.status().contains("jumping to tick"));
+
+        assert_eq!(press(&mut playback.player, KeyCode::Esc), None);
+        assert_eq!(playback.player.target, None);
+        let tick = playback.player.tick;
+        playback.catch_up(Instant::now() + Duration::from_secs(60));
+        assert_eq!(playback.player.tick, tick);
+        assert_eq!(
+            press(&mut playback.player, KeyCode::Esc),
+            Some(Action::Quit)
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! The runner's config file, holding overrides for each day's puzzle parameters and where to find
+//! puzzle inputs.
+//!
+//! Each day's parameters live in a `[dayNN]` table, and the input settings in an `[inputs]` table,
+//! for example:
+//!
+//! ```toml
+//! [inputs]
+//! profile = "alice"
+//!
+//! [day07]
+//! workers = 5
+//! base_duration = 0
+//! ```
+
+use crate::days::DAYS;
+use serde::Deserialize;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// The file the config is read from when no other file is given, if it exists.
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+/// Where puzzle inputs are looked up, from the `[inputs]` table.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct InputSettings {
+    /// The directory holding each profile's inputs, defaulting to `~/.cache/aoc/2018`.
+    pub cache_dir: Option<PathBuf>,
+    /// The profile to read inputs from when none is given on the command line.
+    pub profile: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    table: Table,
+}
+
+impl Config {
+    /// Reads the config from `path`, or from `aoc.toml` in the current directory if no path is
+    /// given and that file exists.
+    pub fn load(path: Option<&Path>) -> Result<Config, Box<dyn Error>> {
+        let path = match path {
+            Some(path) => path.to_owned(),
+            None if Path::new(DEFAULT_PATH).is_file() => PathBuf::from(DEFAULT_PATH),
+            None => return Ok(Config::default()),
+        };
+
+        let text = fs::read_to_string(&path)
+            .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+        Config::parse(&text)
+            .map_err(|err| format!("Invalid config {}: {}", path.display(), err).into())
+    }
+
+    /// Loads the config like [`Config::load`], then applies `--set` overrides on top of it.
+    pub fn load_with_overrides(
+        path: Option<&Path>,
+        overrides: &[String],
+    ) -> Result<Config, Box<dyn Error>> {
+        let mut config = Config::load(path)?;
+        for assignment in overrides {
+            config.set(assignment)?;
+        }
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> Result<Config, Box<dyn Error>> {
+        let table: Table = text.parse()?;
+        for (section, value) in &table {
+            check_section(section)?;
+            if !value.is_table() {
+                return Err(format!("`{}` in the config is not a table", section).into());
+            }
+        }
+        Ok(Config { table })
+    }
+
+    /// Applies an override like `day07.workers=5`. Values are read as TOML, falling back to a
+    /// plain string if they aren't valid TOML.
+    pub fn set(&mut self, assignment: &str) -> Result<(), Box<dyn Error>> {
+        let (key, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("Expected `day.key=value`, found `{}`", assignment))?;
+        let (section, key) = key
+            .trim()
+            .split_once('.')
+            .ok_or_else(|| format!("Expected `day.key=value`, found `{}`", assignment))?;
+        let section = section.trim();
+        check_section(section)?;
+
+        let value = match format!("value = {}", value.trim()).parse::<Table>() {
+            Ok(mut table) => table.remove("value").unwrap(),
+            Err(_) => Value::String(value.trim().to_owned()),
+        };
+
+        match self
+            .table
+            .entry(section.to_owned())
+            .or_insert_with(|| Value::Table(Table::new()))
+        {
+            Value::Table(table) => {
+                table.insert(key.to_owned(), value);
+                Ok(())
+            }
+            _ => Err(format!("`{}` in the config is not a table", section).into()),
+        }
+    }
+
+    /// Where to look up puzzle inputs, which are all left unset if the config has no `[inputs]`
+    /// table.
+    pub fn inputs(&self) -> Result<InputSettings, Box<dyn Error>> {
+        match self.table.get("inputs") {
+            Some(inputs) => InputSettings::deserialize(inputs.clone()).map_err(|err| {
+                let message = err.to_string().replace('\n', " ");
+                format!("Invalid `[inputs]` in the config: {}", message.trim_end()).into()
+            }),
+            None => Ok(InputSettings::default()),
+        }
+    }
+
+    /// The parameters for a single day, which are empty if the config doesn't mention the day.
+    pub fn day(&self, day: u8) -> Table {
+        match self.table.get(&format!("day{:02}", day)) {
+            Some(Value::Table(table)) => table.clone(),
+            _ => Table::new(),
+        }
+    }
+}
+
+/// Makes sure `section` is one the runner reads, so that a typo doesn't silently leave a day's
+/// parameters at their defaults.
+fn check_section(section: &str) -> Result<(), String> {
+    let is_day = |name: &str| {
+        DAYS.iter()
+            .any(|day| name == format!("day{:02}", day.number))
+    };
+    if section == "inputs" || is_day(section) {
+        return Ok(());
+    }
+
+    let mut message = format!("Unknown section `{}` in the config", section);
+    let padded = section
+        .strip_prefix("day")
+        .and_then(|number| number.parse::<u8>().ok())
+        .map(|number| format!("day{:02}", number))
+        .filter(|padded| is_day(padded));
+    match padded {
+        Some(padded) => message += &format!(", did you mean `{}`?", padded),
+        None => {
+            let last = DAYS.last().map_or(0, |day| day.number);
+            message += &format!(", expected `inputs` or `day01` to `day{:02}`", last);
+        }
+    }
+    Err(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, InputSettings};
+    use std::path::PathBuf;
+    use toml::Value;
+
+    #[test]
+    fn overrides() {
+        let mut config = Config::parse("[day07]\nworkers = 5\nbase_duration = 60").unwrap();
+        config.set("day07.base_duration=0").unwrap();
+        config.set("day12.part_one_generations = 30").unwrap();
+
+        let day07 = config.day(7);
+        assert_eq!(day07["workers"], Value::Integer(5));
+        assert_eq!(day07["base_duration"], Value::Integer(0));
+        assert_eq!(config.day(12)["part_one_generations"], Value::Integer(30));
+        assert!(config.day(1).is_empty());
+        assert!(config.set("workers=5").is_err());
+    }
+
+    #[test]
+    fn unknown_sections() {
+        let err = Config::default().set("day7.workers=1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown section `day7` in the config, did you mean `day07`?"
+        );
+        let err = Config::parse("[dya07]\nworkers = 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown section `dya07` in the config, expected `inputs` or `day01` to `day14`"
+        );
+        assert!(Config::parse("[day15]").is_err());
+        assert!(Config::parse("day07 = 5").is_err());
+        assert!(Config::default().set("day99.workers=1").is_err());
+    }
+
+    #[test]
+    fn input_settings() {
+        let mut config = Config::parse("[inputs]\ncache_dir = \"~/inputs\"").unwrap();
+        config.set("inputs.profile=alice").unwrap();
+        let inputs = config.inputs().unwrap();
+        assert_eq!(inputs.cache_dir, Some(PathBuf::from("~/inputs")));
+        assert_eq!(inputs.profile.as_deref(), Some("alice"));
+        assert_eq!(
+            Config::default().inputs().unwrap(),
+            InputSettings::default()
+        );
+        assert!(Config::parse("[inputs]\nprofiles = 1")
+            .unwrap()
+            .inputs()
+            .is_err());
+    }
+}
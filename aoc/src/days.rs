@@ -0,0 +1,270 @@
+use aoc_common::{Animate, Generator, Image, Render, Rng, Simulation, Solution};
+use serde::Deserialize;
+use std::error::Error;
+use std::time::{Duration, Instant};
+use toml::{Table, Value};
+use tracing::{debug, info_span};
+
+/// Solves the given parts of a day's puzzle for the given input, with the day's parameters
+/// overridden by the given config table.
+pub type Solver = fn(&str, &[u8], &Table) -> Result<Solved, Box<dyn Error>>;
+
+/// Makes up an input of roughly the given size from the given random number generator.
+pub type Generate = fn(usize, &mut Rng) -> String;
+
+/// Draws a picture of a day's puzzle for the given input, with the day's parameters overridden by
+/// the given config table.
+pub type Renderer = fn(&str, &Table) -> Result<Image, Box<dyn Error>>;
+
+/// Starts a day's simulation for the given input, with the day's parameters overridden by the
+/// given config table.
+pub type Animator = fn(&str, &Table) -> Result<Box<dyn Simulation>, Box<dyn Error>>;
+
+/// The answers to a day's puzzle, along with how long it took to find them.
+#[derive(Clone)]
+pub struct Solved {
+    /// How long parsing the input took.
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Clone)]
+pub struct Answer {
+    pub part: u8,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+impl Solved {
+    /// The time taken to parse the input and solve every part.
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .answers
+                .iter()
+                .map(|answer| answer.duration)
+                .sum::<Duration>()
+    }
+}
+
+/// A single day's puzzle, as seen by the runner.
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    pub run: Solver,
+    /// Makes up inputs for the day, if it has a generator.
+    pub generate: Option<Generate>,
+    /// Draws the day's puzzle, if it can be drawn.
+    pub render: Option<Renderer>,
+    /// Plays back the day's simulation, if it is one.
+    pub animate: Option<Animator>,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Day {
+        Day {
+            number: S::DAY,
+            input: S::INPUT,
+            run: solve::<S>,
+            generate: None,
+            render: None,
+            animate: None,
+        }
+    }
+
+    const fn with_generator<G: Generator>(self) -> Day {
+        Day {
+            generate: Some(G::generate),
+            ..self
+        }
+    }
+
+    const fn with_renderer<R: Render>(self) -> Day {
+        Day {
+            render: Some(render::<R>),
+            ..self
+        }
+    }
+
+    const fn with_animation<A: Animate>(self) -> Day {
+        Day {
+            animate: Some(animate::<A>),
+            ..self
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>().with_generator::<day01::Day01>(),
+    Day::new::<day02::Day02>().with_generator::<day02::Day02>(),
+    Day::new::<day03::Day03>()
+        .with_generator::<day03::Day03>()
+        .with_renderer::<day03::Day03>(),
+    Day::new::<day04::Day04>().with_generator::<day04::Day04>(),
+    Day::new::<day05::Day05>().with_generator::<day05::Day05>(),
+    Day::new::<day06::Day06>()
+        .with_generator::<day06::Day06>()
+        .with_renderer::<day06::Day06>(),
+    Day::new::<day07::Day07>().with_generator::<day07::Day07>(),
+    Day::new::<day08::Day08>().with_generator::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>()
+        .with_generator::<day10::Day10>()
+        .with_renderer::<day10::Day10>()
+        .with_animation::<day10::Day10>(),
+    Day::new::<day11::Day11>().with_renderer::<day11::Day11>(),
+    Day::new::<day12::Day12>().with_animation::<day12::Day12>(),
+    Day::new::<day13::Day13>()
+        .with_generator::<day13::Day13>()
+        .with_renderer::<day13::Day13>()
+        .with_animation::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8], config: &Table) -> Result<Solved, Box<dyn Error>> {
+    let _day = info_span!("day", day = S::DAY).entered();
+    let config = read_config::<S>(config)?;
+
+    let start = Instant::now();
+    let puzzle = info_span!("parse").in_scope(|| S::parse(input))?;
+    let parse = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let _part = info_span!("part", part).entered();
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part_one(&puzzle, &config)?.to_string(),
+                2 => S::part_two(&puzzle, &config)?.to_string(),
+                _ => return Err(format!("Day {} has no part {}", S::DAY, part).into()),
+            };
+
+            let duration = start.elapsed();
+            debug!(?duration, %answer, "solved");
+            Ok(Answer {
+                part,
+                answer,
+                duration,
+            })
+        })
+        .collect::<Result<_, Box<dyn Error>>>()?;
+
+    Ok(Solved { parse, answers })
+}
+
+fn render<R: Render>(input: &str, config: &Table) -> Result<Image, Box<dyn Error>> {
+    let config = read_config::<R>(config)?;
+    let puzzle = R::parse(input)?;
+    R::render(&puzzle, &config)
+}
+
+fn animate<A: Animate>(input: &str, config: &Table) -> Result<Box<dyn Simulation>, Box<dyn Error>> {
+    let config = read_config::<A>(config)?;
+    let puzzle = A::parse(input)?;
+    A::animate(&puzzle, &config)
+}
+
+fn read_config<S: Solution>(config: &Table) -> Result<S::Config, Box<dyn Error>> {
+    S::Config::deserialize(Value::Table(config.clone())).map_err(|err| {
+        let message = err.to_string().replace('\n', " ");
+        format!("Invalid config for day {}: {}", S::DAY, message.trim_end()).into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DAYS;
+    use aoc_common::Rng;
+    use toml::Table;
+
+    /// Solves every day's bundled input and compares the answers against `answers.toml`, so
+    /// refactoring a day can't silently change its results.
+    #[test]
+    fn answers_match_snapshot() {
+        let answers: toml::Table = include_str!("../../answers.toml").parse().unwrap();
+
+        let mut mismatches = Vec::new();
+        for day in DAYS {
+            let key = format!("day{:02}", day.number);
+            for part in 1..=2 {
+                let expected = answers
+                    .get(&key)
+                    .and_then(|day| day.get(format!("part{}", part)))
+                    .and_then(|answer| answer.as_str())
+                    .unwrap_or_else(|| panic!("answers.toml has no {}.part{}", key, part));
+
+                match (day.run)(day.input, &[part], &Table::new()) {
+                    Ok(solved) if solved.answers[0].answer == expected => {}
+                    Ok(solved) => mismatches.push(format!(
+                        "Day {:02} Part {}: expected {}, got {}",
+                        day.number, part, expected, solved.answers[0].answer
+                    )),
+                    Err(err) => mismatches.push(format!(
+                        "Day {:02} Part {}: expected {}, got error: {}",
+                        day.number, part, expected, err
+                    )),
+                }
+            }
+        }
+
+        assert!(mismatches.is_empty(), "\n{}", mismatches.join("\n"));
+    }
+
+    /// Every generator should make inputs its day can parse, and the same input for the same seed.
+    #[test]
+    fn generated_inputs_parse() {
+        for day in DAYS {
+            let generate = match day.generate {
+                Some(generate) => generate,
+                None => continue,
+            };
+            for &size in &[0, 1, 2, 10, 100] {
+                for seed in 0..3 {
+                    let input = generate(size, &mut Rng::new(seed));
+                    assert_eq!(input, generate(size, &mut Rng::new(seed)));
+                    if let Err(err) = (day.run)(&input, &[], &Table::new()) {
+                        panic!("size {}, seed {}: {}\n{}", size, seed, err, input);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bundled_inputs_render() {
+        let renderable = DAYS.iter().filter_map(|day| Some((day, day.render?)));
+        for (day, render) in renderable {
+            let image = render(day.input, &Table::new())
+                .unwrap_or_else(|err| panic!("Day {}: {}", day.number, err));
+            assert!(image.width() > 0 && image.height() > 0);
+        }
+    }
+
+    #[test]
+    fn bundled_inputs_animate() {
+        let animated = DAYS.iter().filter_map(|day| Some((day, day.animate?)));
+        for (day, animate) in animated {
+            let mut simulation = animate(day.input, &Table::new()).unwrap();
+            for _ in 0..10 {
+                assert!(
+                    simulation.step().unwrap(),
+                    "Day {} stopped early",
+                    day.number
+                );
+            }
+            let frame = simulation.frame(80, 24);
+            assert!(
+                frame.lines().count() <= 24,
+                "Day {}:\n{}",
+                day.number,
+                frame
+            );
+            assert!(frame.lines().all(|line| line.chars().count() <= 80));
+        }
+    }
+}
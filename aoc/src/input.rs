@@ -0,0 +1,206 @@
+use crate::config::InputSettings;
+use std::borrow::Cow;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a day's puzzle input comes from.
+pub enum Input {
+    /// The input compiled into the day's crate.
+    Bundled,
+    /// A file on disk.
+    File(PathBuf),
+    /// Everything written to standard input.
+    Stdin,
+    /// Text given directly on the command line.
+    Inline(String),
+    /// A profile's input for the day, from the input cache.
+    Cached(Profile),
+}
+
+impl Input {
+    pub fn new(file: Option<PathBuf>, inline: Option<String>, profile: Option<Profile>) -> Input {
+        match (file, inline, profile) {
+            (_, Some(text), _) => Input::Inline(text),
+            (Some(ref path), None, _) if path.as_os_str() == "-" => Input::Stdin,
+            (Some(path), None, _) => Input::File(path),
+            (None, None, Some(profile)) => Input::Cached(profile),
+            (None, None, None) => Input::Bundled,
+        }
+    }
+
+    /// Reads the input for `day`, falling back to `bundled` when no other input was given.
+    pub fn read(&self, day: u8, bundled: &'static str) -> io::Result<Cow<'static, str>> {
+        match self {
+            Input::Bundled => Ok(Cow::Borrowed(bundled)),
+            Input::File(path) => fs::read_to_string(path).map(Cow::Owned).map_err(|err| {
+                io::Error::new(
+                    err.kind(),
+                    format!("Could not read {}: {}", path.display(), err),
+                )
+            }),
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Cow::Owned(input))
+            }
+            Input::Inline(text) => Ok(Cow::Owned(text.clone())),
+            Input::Cached(profile) => profile.read(day).map(Cow::Owned),
+        }
+    }
+}
+
+/// One person's puzzle inputs, kept side by side with everyone else's in the input cache as
+/// `<cache dir>/<profile>/dayNN.txt`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    /// The directory holding every profile.
+    pub cache_dir: PathBuf,
+}
+
+impl Profile {
+    /// Picks the profile named on the command line, or else the one set in the config, or none
+    /// at all if neither names one.
+    pub fn select(
+        name: Option<String>,
+        settings: &InputSettings,
+    ) -> Result<Option<Profile>, Box<dyn Error>> {
+        let name = match name.or_else(|| settings.profile.clone()) {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+            return Err(format!("`{}` is not a valid profile name.", name).into());
+        }
+
+        let cache_dir = match &settings.cache_dir {
+            Some(dir) => expand_home(dir),
+            None => default_cache_dir().ok_or(
+                "Could not find a cache directory for inputs, set `cache_dir` under `[inputs]` in \
+                 aoc.toml.",
+            )?,
+        };
+        Ok(Some(Profile { name, cache_dir }))
+    }
+
+    /// Where the profile's input for `day` is kept.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(&self.name)
+            .join(format!("day{:02}.txt", day))
+    }
+
+    fn read(&self, day: u8) -> io::Result<String> {
+        let path = self.path(day);
+        fs::read_to_string(&path).map_err(|err| {
+            if err.kind() != io::ErrorKind::NotFound {
+                return io::Error::new(
+                    err.kind(),
+                    format!("Could not read {}: {}", path.display(), err),
+                );
+            }
+
+            let mut message = format!(
+                "Profile `{}` has no input for day {}, expected it at {}.",
+                self.name,
+                day,
+                path.display()
+            );
+            if !self.cache_dir.join(&self.name).is_dir() {
+                let profiles = self.others();
+                if profiles.is_empty() {
+                    message += &format!(" There are no profiles in {}.", self.cache_dir.display());
+                } else {
+                    message += &format!(" The profiles are: {}.", profiles.join(", "));
+                }
+            }
+            io::Error::new(io::ErrorKind::NotFound, message)
+        })
+    }
+
+    /// The names of every profile in the cache directory, in alphabetical order.
+    fn others(&self) -> Vec<String> {
+        let mut profiles = fs::read_dir(&self.cache_dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect::<Vec<_>>();
+        profiles.sort();
+        profiles
+    }
+}
+
+/// `$XDG_CACHE_HOME/aoc/2018`, or `~/.cache/aoc/2018` if that isn't set.
+fn default_cache_dir() -> Option<PathBuf> {
+    let cache = env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| Some(PathBuf::from(env::var_os("HOME")?).join(".cache")))?;
+    Some(cache.join("aoc").join("2018"))
+}
+
+/// Expands a leading `~` in a configured path to the home directory.
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Input, Profile};
+    use crate::config::InputSettings;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn settings(cache_dir: &str, profile: Option<&str>) -> InputSettings {
+        InputSettings {
+            cache_dir: Some(PathBuf::from(cache_dir)),
+            profile: profile.map(String::from),
+        }
+    }
+
+    #[test]
+    fn profiles_are_selected() {
+        let config = settings("/inputs", Some("alice"));
+        let alice = Profile::select(None, &config).unwrap().unwrap();
+        assert_eq!(alice.path(9), PathBuf::from("/inputs/alice/day09.txt"));
+
+        let bob = Profile::select(Some("bob".into()), &config)
+            .unwrap()
+            .unwrap();
+        assert_eq!(bob.name, "bob");
+        assert_eq!(
+            Profile::select(None, &settings("/inputs", None)).unwrap(),
+            None
+        );
+        assert!(Profile::select(Some("../bob".into()), &config).is_err());
+    }
+
+    #[test]
+    fn cached_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        fs::create_dir_all(dir.join("carol")).unwrap();
+        fs::write(dir.join("alice").join("day01.txt"), "+1\n").unwrap();
+        let input = |name: &str| {
+            let config = settings(dir.to_str().unwrap(), Some(name));
+            Input::new(None, None, Profile::select(None, &config).unwrap())
+        };
+
+        assert_eq!(input("alice").read(1, "bundled").unwrap(), "+1\n");
+        let missing_day = input("alice").read(2, "bundled").unwrap_err().to_string();
+        assert!(missing_day.starts_with("Profile `alice` has no input for day 2, expected it at"));
+        assert!(missing_day.ends_with("day02.txt."));
+        let missing_profile = input("bob").read(1, "bundled").unwrap_err().to_string();
+        assert!(missing_profile.ends_with(" The profiles are: alice, carol."));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
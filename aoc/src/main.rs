@@ -0,0 +1,388 @@
+use aoc_common::Rng;
+use clap::{Parser, Subcommand};
+use std::error::Error;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+mod animate;
+mod config;
+mod days;
+mod input;
+mod output;
+mod parallel;
+mod watch;
+
+use crate::config::Config;
+use crate::days::Day;
+use crate::input::{Input, Profile};
+use crate::output::Format;
+use crate::parallel::Outcome;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2018 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log what the solvers are doing to stderr, `-vv` for every step of their loops. `RUST_LOG`
+    /// takes priority, such as `RUST_LOG=day07=trace`
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with `--all`
+    Run {
+        /// The day to solve
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+
+        /// Only solve the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Solve every day
+        #[arg(long)]
+        all: bool,
+
+        /// Read the puzzle input from a file instead of the bundled input, `-` reads stdin
+        #[arg(short, long, value_name = "FILE", conflicts_with_all = ["all", "inline"])]
+        input: Option<PathBuf>,
+
+        /// Use the given text as the puzzle input
+        #[arg(long, value_name = "TEXT", conflicts_with = "all")]
+        inline: Option<String>,
+
+        /// Read inputs from the given profile in the input cache, rather than the bundled inputs
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["input", "inline"])]
+        profile: Option<String>,
+
+        /// Print how long parsing and each part took once everything is solved
+        #[arg(short, long)]
+        time: bool,
+
+        /// How to print the answers
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Solve the days concurrently, reporting days that fail or panic instead of stopping
+        #[arg(long, requires = "all")]
+        parallel: bool,
+
+        /// How many threads to solve days on with `--parallel`, defaults to one per CPU
+        #[arg(short, long, value_name = "N", requires = "parallel")]
+        jobs: Option<usize>,
+
+        /// Read puzzle parameters from the given TOML file instead of `aoc.toml`
+        #[arg(short, long, value_name = "FILE")]
+        config: Option<PathBuf>,
+
+        /// Override a puzzle parameter, such as `day07.workers=5`
+        #[arg(long = "set", value_name = "DAY.KEY=VALUE")]
+        overrides: Vec<String>,
+
+        /// Also draw the puzzle to a .ppm, .png or .svg file, for days that can be drawn
+        #[arg(long, value_name = "FILE", conflicts_with = "all")]
+        render: Option<PathBuf>,
+
+        /// Draw each of the puzzle's pixels as a square this many pixels across
+        #[arg(long, value_name = "N", default_value_t = 1, requires = "render",
+              value_parser = clap::value_parser!(u16).range(1..))]
+        scale: u16,
+    },
+
+    /// Play back a simulation day's steps in the terminal
+    Animate {
+        /// The day to play back
+        day: u8,
+
+        /// How many steps to play a second
+        #[arg(long, default_value_t = 10.0)]
+        speed: f64,
+
+        /// Jump straight to the given tick
+        #[arg(long, value_name = "TICK", default_value_t = 0)]
+        start: u64,
+
+        /// Start paused
+        #[arg(long)]
+        paused: bool,
+
+        /// Read the puzzle input from a file instead of the bundled input
+        #[arg(short, long, value_name = "FILE", conflicts_with = "inline")]
+        input: Option<PathBuf>,
+
+        /// Use the given text as the puzzle input
+        #[arg(long, value_name = "TEXT")]
+        inline: Option<String>,
+
+        /// Read inputs from the given profile in the input cache, rather than the bundled inputs
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["input", "inline"])]
+        profile: Option<String>,
+
+        /// Read puzzle parameters from the given TOML file instead of `aoc.toml`
+        #[arg(short, long, value_name = "FILE")]
+        config: Option<PathBuf>,
+
+        /// Override a puzzle parameter, such as `day13.max_ticks=500`
+        #[arg(long = "set", value_name = "DAY.KEY=VALUE")]
+        overrides: Vec<String>,
+    },
+
+    /// Solve a day, then solve it again whenever its input or config file changes
+    Watch {
+        /// The day to solve
+        day: u8,
+
+        /// Only solve the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// The puzzle input to watch, defaults to the `input.txt` in the day's crate
+        #[arg(short, long, value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Watch the given profile's input in the input cache
+        #[arg(long, value_name = "NAME", conflicts_with = "input")]
+        profile: Option<String>,
+
+        /// Read puzzle parameters from the given TOML file instead of `aoc.toml`
+        #[arg(short, long, value_name = "FILE")]
+        config: Option<PathBuf>,
+
+        /// Override a puzzle parameter, such as `day07.workers=5`
+        #[arg(long = "set", value_name = "DAY.KEY=VALUE")]
+        overrides: Vec<String>,
+
+        /// How often to check the files for changes, in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = 500)]
+        interval: u64,
+    },
+
+    /// Make up an input for a day and print it, for testing how its solution scales
+    Gen {
+        /// The day to make an input for
+        day: u8,
+
+        /// Roughly how many items, such as lines or tree nodes, the input should have
+        #[arg(short, long, default_value_t = 1000)]
+        size: usize,
+
+        /// The seed for the random choices, so the same seed always makes the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    if let Err(err) = run(cli) {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    }
+}
+
+/// Sends the solvers' tracing events to stderr, filtered by `RUST_LOG` if it is set and by the
+/// number of `-v` flags otherwise.
+fn init_logging(verbose: u8) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        EnvFilter::new(match verbose {
+            0 => "warn",
+            1 => "debug",
+            _ => "trace",
+        })
+    });
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all,
+            input,
+            inline,
+            profile,
+            time,
+            format,
+            parallel,
+            jobs,
+            config,
+            overrides,
+            render,
+            scale,
+        } => {
+            let config = Config::load_with_overrides(config.as_deref(), &overrides)?;
+            let profile = Profile::select(profile, &config.inputs()?)?;
+            let input = Input::new(input, inline, profile);
+
+            let days: Vec<&Day> = if all {
+                days::DAYS.iter().collect()
+            } else {
+                let day = day.expect("clap requires a day unless --all is given");
+                vec![days::find(day).ok_or_else(|| format!("Day {} is not solved yet.", day))?]
+            };
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+
+            if parallel {
+                return run_parallel(&days, &input, &parts, &config, jobs, format);
+            }
+
+            let mut solved = Vec::new();
+            for day in days {
+                let puzzle_input = input.read(day.number, day.input)?;
+                // Drawing comes first, since a picture is most useful when the puzzle can't be
+                // solved.
+                if let Some(path) = &render {
+                    render_day(day, &puzzle_input, &config, path, scale)?;
+                }
+                let day_solved = (day.run)(&puzzle_input, &parts, &config.day(day.number))?;
+                if format == Format::Text {
+                    for answer in &day_solved.answers {
+                        output::print_answer(day.number, answer.part, &answer.answer);
+                    }
+                }
+                solved.push((day.number, day_solved));
+            }
+
+            match format {
+                Format::Text if time => output::print_timings(&solved),
+                Format::Text => {}
+                Format::Json => output::print_json(&solved)?,
+                Format::Csv => output::print_csv(&solved),
+            }
+        }
+        Command::Animate {
+            day,
+            speed,
+            start,
+            paused,
+            input,
+            inline,
+            profile,
+            config,
+            overrides,
+        } => {
+            let config = Config::load_with_overrides(config.as_deref(), &overrides)?;
+            let profile = Profile::select(profile, &config.inputs()?)?;
+            let day = days::find(day).ok_or_else(|| format!("Day {} is not solved yet.", day))?;
+            let animate = day
+                .animate
+                .ok_or_else(|| format!("Day {} can't be animated.", day.number))?;
+            let input = Input::new(input, inline, profile).read(day.number, day.input)?;
+            let table = config.day(day.number);
+            animate::play(&|| animate(&input, &table), start, speed, paused)?;
+        }
+        Command::Watch {
+            day,
+            part,
+            input,
+            profile,
+            config,
+            overrides,
+            interval,
+        } => {
+            let day = days::find(day).ok_or_else(|| format!("Day {} is not solved yet.", day))?;
+            let settings = Config::load_with_overrides(config.as_deref(), &overrides)?.inputs()?;
+            let input = match (input, Profile::select(profile, &settings)?) {
+                (Some(input), _) => input,
+                (None, Some(profile)) => profile.path(day.number),
+                (None, None) => watch::bundled_input_path(day.number),
+            };
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            watch::watch(
+                day,
+                input,
+                &parts,
+                config.as_deref(),
+                &overrides,
+                Duration::from_millis(interval),
+            )?;
+        }
+        Command::Gen { day, size, seed } => {
+            let generate = days::find(day)
+                .and_then(|day| day.generate)
+                .ok_or_else(|| format!("Day {} has no input generator.", day))?;
+            print!("{}", generate(size, &mut Rng::new(seed)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Draws a day's puzzle for the given input and saves the picture to `path`.
+fn render_day(
+    day: &Day,
+    input: &str,
+    config: &Config,
+    path: &Path,
+    scale: u16,
+) -> Result<(), Box<dyn Error>> {
+    let render = day
+        .render
+        .ok_or_else(|| format!("Day {} can't be rendered.", day.number))?;
+    let image = render(input, &config.day(day.number))?;
+    image
+        .scaled(usize::from(scale))?
+        .save(path)
+        .map_err(|err| format!("Could not save {}: {}", path.display(), err))?;
+    Ok(())
+}
+
+/// Solves every day at once, printing the answers of the days that succeeded followed by a
+/// summary of every day.
+fn run_parallel(
+    days: &[&Day],
+    input: &Input,
+    parts: &[u8],
+    config: &Config,
+    jobs: Option<usize>,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let reports = parallel::solve_all(days, input, parts, config, jobs)?;
+    let failures = reports.iter().filter(|report| !report.is_success()).count();
+
+    let solved = reports
+        .iter()
+        .filter_map(|report| match &report.outcome {
+            Outcome::Solved(solved) => Some((report.day, solved.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    match format {
+        Format::Text => {
+            for (day, solved) in &solved {
+                for answer in &solved.answers {
+                    output::print_answer(*day, answer.part, &answer.answer);
+                }
+            }
+            output::print_summary(&reports);
+        }
+        Format::Json => output::print_json(&solved)?,
+        Format::Csv => output::print_csv(&solved),
+    }
+
+    if failures > 0 {
+        return Err(format!("{} of {} days failed", failures, reports.len()).into());
+    }
+    Ok(())
+}
@@ -0,0 +1,153 @@
+//! Printing answers, either for people to read or as records for other tools to consume.
+
+use crate::days::Solved;
+use crate::parallel::{Outcome, Report};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// `Day NN Part P: answer` lines, printed as each day is solved
+    Text,
+    /// A JSON array of `{day, part, answer, duration}` records
+    Json,
+    /// `day,part,answer,duration` rows with a header
+    Csv,
+}
+
+/// A single solved part, as written in JSON and CSV output.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    /// How long solving the part took, in seconds.
+    duration: f64,
+}
+
+fn records(solved: &[(u8, Solved)]) -> impl Iterator<Item = Record<'_>> {
+    solved.iter().flat_map(|(day, solved)| {
+        solved.answers.iter().map(move |answer| Record {
+            day: *day,
+            part: answer.part,
+            answer: &answer.answer,
+            duration: answer.duration.as_secs_f64(),
+        })
+    })
+}
+
+/// Prints every answer as a pretty printed JSON array.
+pub fn print_json(solved: &[(u8, Solved)]) -> serde_json::Result<()> {
+    let records = records(solved).collect::<Vec<_>>();
+    println!("{}", serde_json::to_string_pretty(&records)?);
+    Ok(())
+}
+
+/// Prints every answer as a CSV row, quoting answers that span multiple lines.
+pub fn print_csv(solved: &[(u8, Solved)]) {
+    println!("day,part,answer,duration");
+    for record in records(solved) {
+        println!(
+            "{},{},{},{}",
+            record.day,
+            record.part,
+            csv_field(record.answer),
+            record.duration
+        );
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Prints an answer as `Day NN Part P: answer`, moving multi-line answers onto their own lines.
+pub fn print_answer(day: u8, part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("Day {:02} Part {}:\n{}", day, part, answer);
+    } else {
+        println!("Day {:02} Part {}: {}", day, part, answer);
+    }
+}
+
+/// Prints a table of how long each day took to parse and solve, with a total for every day.
+pub fn print_timings(timings: &[(u8, Solved)]) {
+    println!();
+    println!(
+        "{:<5} {:>10} {:>10} {:>10} {:>10}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    for (day, solved) in timings {
+        let part = |part| {
+            solved
+                .answers
+                .iter()
+                .find(|answer| answer.part == part)
+                .map(|answer| format_duration(answer.duration))
+                .unwrap_or_else(|| "-".to_owned())
+        };
+        println!(
+            "{:<5} {:>10} {:>10} {:>10} {:>10}",
+            format!("{:02}", day),
+            format_duration(solved.parse),
+            part(1),
+            part(2),
+            format_duration(solved.total())
+        );
+    }
+
+    let total = timings.iter().map(|(_, solved)| solved.total()).sum();
+    println!("{:<5} {:>43}", "Total", format_duration(total));
+}
+
+/// Prints a table of which days were solved and which failed, along with how long each took.
+pub fn print_summary(reports: &[Report]) {
+    println!();
+    println!("{:<5} {:<8} {:>10}  Details", "Day", "Status", "Time");
+
+    for report in reports {
+        let (status, details) = match &report.outcome {
+            Outcome::Solved(_) => ("ok", String::new()),
+            Outcome::Failed(err) => ("error", err.clone()),
+            Outcome::Panicked(message) => ("panic", message.clone()),
+        };
+        let row = format!(
+            "{:<5} {:<8} {:>10}  {}",
+            format!("{:02}", report.day),
+            status,
+            format_duration(report.elapsed),
+            details.lines().next().unwrap_or("")
+        );
+        println!("{}", row.trim_end());
+    }
+
+    let solved = reports.iter().filter(|report| report.is_success()).count();
+    println!(
+        "{} of {} days solved, {} failed",
+        solved,
+        reports.len(),
+        reports.len() - solved
+    );
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::csv_field;
+
+    #[test]
+    fn csv_quoting() {
+        assert_eq!(csv_field("1157"), "1157");
+        assert_eq!(csv_field("34,13"), "\"34,13\"");
+        assert_eq!(csv_field(".#\n#\""), "\".#\n#\"\"\"");
+    }
+}
@@ -0,0 +1,170 @@
+//! Solving many days at once, keeping a failure in one day from hiding the results of the others.
+
+use crate::config::Config;
+use crate::days::{Day, Solved};
+use crate::input::Input;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use std::any::Any;
+use std::cell::Cell;
+use std::error::Error;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+/// How solving a single day went.
+pub enum Outcome {
+    Solved(Solved),
+    /// The day returned an error.
+    Failed(String),
+    /// The day panicked.
+    Panicked(String),
+}
+
+pub struct Report {
+    pub day: u8,
+    pub outcome: Outcome,
+    /// How long the day ran for, including reading its input.
+    pub elapsed: Duration,
+}
+
+impl Report {
+    pub fn is_success(&self) -> bool {
+        matches!(self.outcome, Outcome::Solved(_))
+    }
+}
+
+/// Solves every day on a thread pool with `jobs` threads, or one per CPU if `jobs` isn't given.
+///
+/// Errors and panics are caught and reported per day rather than stopping the run. The reports
+/// are returned in the same order as `days`.
+pub fn solve_all(
+    days: &[&Day],
+    input: &Input,
+    parts: &[u8],
+    config: &Config,
+    jobs: Option<usize>,
+) -> Result<Vec<Report>, Box<dyn Error>> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()?;
+
+    let reports = pool.install(|| {
+        days.par_iter()
+            .map(|day| solve_day(day, input, parts, config))
+            .collect()
+    });
+
+    Ok(reports)
+}
+
+thread_local! {
+    /// Whether `solve_day` is catching panics on this thread.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook so that it stays quiet about the panics `solve_day` catches, which are
+/// reported along with the day instead of being printed over each other as they happen. Panics
+/// anywhere else still go to the hook that was there before.
+fn quiet_caught_panics() {
+    static WRAP: Once = Once::new();
+    WRAP.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+/// Solves a single day, catching any error or panic.
+pub fn solve_day(day: &Day, input: &Input, parts: &[u8], config: &Config) -> Report {
+    quiet_caught_panics();
+    let start = Instant::now();
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let puzzle_input = input.read(day.number, day.input)?;
+        (day.run)(&puzzle_input, parts, &config.day(day.number))
+    }));
+    CATCHING.with(|catching| catching.set(was_catching));
+
+    let outcome = match result {
+        Ok(Ok(solved)) => Outcome::Solved(solved),
+        Ok(Err(err)) => Outcome::Failed(err.to_string()),
+        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+    };
+
+    Report {
+        day: day.number,
+        outcome,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve_all, solve_day, Outcome, CATCHING};
+    use crate::config::Config;
+    use crate::days::{Day, Solved, DAYS};
+    use crate::input::Input;
+    use std::cell::Cell;
+    use std::error::Error;
+    use toml::Table;
+
+    fn panics(_: &str, _: &[u8], _: &Table) -> Result<Solved, Box<dyn Error>> {
+        panic!("Bad things happened!")
+    }
+
+    fn fails(_: &str, _: &[u8], _: &Table) -> Result<Solved, Box<dyn Error>> {
+        Err("no answer".into())
+    }
+
+    #[test]
+    fn failures_are_isolated() {
+        let panicking = Day {
+            number: 1,
+            input: "",
+            run: panics,
+            generate: None,
+            render: None,
+            animate: None,
+        };
+        let failing = Day {
+            number: 2,
+            input: "",
+            run: fails,
+            generate: None,
+            render: None,
+            animate: None,
+        };
+        let days = vec![&panicking, &failing, &DAYS[2]];
+
+        let reports = solve_all(&days, &Input::Bundled, &[1], &Config::default(), Some(2)).unwrap();
+        assert_eq!(reports.len(), 3);
+        assert!(
+            matches!(&reports[0].outcome, Outcome::Panicked(message) if message == "Bad things happened!")
+        );
+        assert!(matches!(&reports[1].outcome, Outcome::Failed(message) if message == "no answer"));
+        match &reports[2].outcome {
+            Outcome::Solved(solved) => assert_eq!(solved.answers[0].answer, "118840"),
+            _ => panic!("day 3 should have been solved"),
+        }
+
+        // Catching is switched back off once the day is solved, even when it panics, so later
+        // panics on this thread still go to the previous hook.
+        let report = solve_day(&panicking, &Input::Bundled, &[1], &Config::default());
+        assert!(matches!(report.outcome, Outcome::Panicked(_)));
+        assert!(!CATCHING.with(Cell::get));
+    }
+}
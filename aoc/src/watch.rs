@@ -0,0 +1,240 @@
+//! Re-solving a day whenever its input or config changes, for iterating on alternate inputs.
+
+use crate::config::{self, Config};
+use crate::days::Day;
+use crate::input::Input;
+use crate::parallel::{self, Outcome};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// The input file in a day's crate, which is where the bundled input is compiled from.
+pub fn bundled_input_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner is inside the workspace")
+        .join(format!("day{:02}", day))
+        .join("input.txt")
+}
+
+/// Keeps track of a set of files, noticing when they are written to, created or deleted.
+///
+/// Files are checked by polling their modification time and size, which works the same on every
+/// platform and is plenty fast for a handful of files.
+struct Watcher {
+    files: Vec<(PathBuf, Option<Stamp>)>,
+}
+
+/// When a file was last modified and how big it is, so a change is noticed even if it happens
+/// within the file system's timestamp resolution.
+type Stamp = (SystemTime, u64);
+
+fn stamp(path: &Path) -> Option<Stamp> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+impl Watcher {
+    fn new(paths: Vec<PathBuf>) -> Watcher {
+        Watcher {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let stamp = stamp(&path);
+                    (path, stamp)
+                })
+                .collect(),
+        }
+    }
+
+    /// The files that have changed since the last check.
+    fn changed(&mut self) -> Vec<&Path> {
+        self.files
+            .iter_mut()
+            .filter_map(|(path, last)| {
+                let current = stamp(path);
+                if current == *last {
+                    None
+                } else {
+                    *last = current;
+                    Some(path.as_path())
+                }
+            })
+            .collect()
+    }
+}
+
+/// Describes a part's answer, along with how it compares to the answer from the previous run.
+fn describe(day: u8, part: u8, answer: &str, previous: Option<&str>) -> String {
+    let multi_line = answer.contains('\n') || previous.is_some_and(|p| p.contains('\n'));
+    match previous {
+        None if multi_line => format!("Day {:02} Part {}:\n{}", day, part, answer),
+        None => format!("Day {:02} Part {}: {}", day, part, answer),
+        Some(previous) if previous == answer && multi_line => {
+            format!("Day {:02} Part {} (unchanged):\n{}", day, part, answer)
+        }
+        Some(previous) if previous == answer => {
+            format!("Day {:02} Part {}: {} (unchanged)", day, part, answer)
+        }
+        Some(previous) if multi_line => format!(
+            "Day {:02} Part {} (changed):\n{}\nwas:\n{}",
+            day, part, answer, previous
+        ),
+        Some(previous) => format!(
+            "Day {:02} Part {}: {} (was {})",
+            day, part, answer, previous
+        ),
+    }
+}
+
+/// Solves the day and prints its answers, remembering them in `previous` to compare against next
+/// time. Errors are printed rather than returned, so that watching carries on.
+fn solve(
+    day: &Day,
+    input: &Input,
+    parts: &[u8],
+    config_path: Option<&Path>,
+    overrides: &[String],
+    previous: &mut BTreeMap<u8, String>,
+) {
+    let config = match Config::load_with_overrides(config_path, overrides) {
+        Ok(config) => config,
+        Err(err) => return eprintln!("Error: {}", err),
+    };
+
+    let report = parallel::solve_day(day, input, parts, &config);
+    match report.outcome {
+        Outcome::Solved(solved) => {
+            for answer in solved.answers {
+                let last = previous.get(&answer.part).map(String::as_str);
+                println!(
+                    "{}",
+                    describe(day.number, answer.part, &answer.answer, last)
+                );
+                previous.insert(answer.part, answer.answer);
+            }
+            println!("Solved in {:.2?}", report.elapsed);
+        }
+        Outcome::Failed(err) => eprintln!("Error: {}", err),
+        Outcome::Panicked(message) => eprintln!("Error: day {} panicked: {}", day.number, message),
+    }
+}
+
+/// Solves the day, then solves it again every time its input file or config file changes, until
+/// the process is stopped.
+pub fn watch(
+    day: &Day,
+    input: PathBuf,
+    parts: &[u8],
+    config_path: Option<&Path>,
+    overrides: &[String],
+    interval: Duration,
+) -> Result<(), Box<dyn Error>> {
+    if !input.is_file() {
+        return Err(format!(
+            "Could not find {}, use --input to choose the file to watch.",
+            input.display()
+        )
+        .into());
+    }
+    let watched_config = config_path.unwrap_or_else(|| Path::new(config::DEFAULT_PATH));
+    let mut watcher = Watcher::new(vec![input.clone(), watched_config.to_owned()]);
+
+    println!(
+        "Watching {} and {} for changes",
+        input.display(),
+        watched_config.display()
+    );
+    let input = Input::File(input);
+    let mut previous = BTreeMap::new();
+    solve(day, &input, parts, config_path, overrides, &mut previous);
+
+    loop {
+        thread::sleep(interval);
+        let mut changed = watcher
+            .changed()
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>();
+        if changed.is_empty() {
+            continue;
+        }
+
+        // Editors often save by truncating the file and then writing it, so wait for the files to
+        // stop changing rather than solving a half written input.
+        loop {
+            thread::sleep(interval);
+            let still_changing = watcher.changed();
+            if still_changing.is_empty() {
+                break;
+            }
+            for path in still_changing {
+                let path = path.display().to_string();
+                if !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+        }
+        println!();
+        println!("{} changed, solving again", changed.join(" and "));
+        solve(day, &input, parts, config_path, overrides, &mut previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bundled_input_path, describe, Watcher};
+    use aoc_common::Solution;
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn changes_are_noticed() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("input.txt");
+        let config = dir.join("aoc.toml");
+        fs::write(&input, "+1\n").unwrap();
+
+        let mut watcher = Watcher::new(vec![input.clone(), config.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        fs::write(&input, "+1\n-2\n").unwrap();
+        assert_eq!(watcher.changed(), vec![input.as_path()]);
+        assert!(watcher.changed().is_empty());
+
+        fs::write(&config, "[day01]\n").unwrap();
+        assert_eq!(watcher.changed(), vec![config.as_path()]);
+        fs::remove_file(&config).unwrap();
+        assert_eq!(watcher.changed(), vec![config.as_path()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn answers_are_compared() {
+        assert_eq!(describe(7, 1, "ABC", None), "Day 07 Part 1: ABC");
+        assert_eq!(
+            describe(7, 1, "ABC", Some("ABC")),
+            "Day 07 Part 1: ABC (unchanged)"
+        );
+        assert_eq!(
+            describe(7, 2, "1000", Some("998")),
+            "Day 07 Part 2: 1000 (was 998)"
+        );
+        assert_eq!(
+            describe(10, 1, "#.\n.#", Some(".#\n#.")),
+            "Day 10 Part 1 (changed):\n#.\n.#\nwas:\n.#\n#."
+        );
+    }
+
+    #[test]
+    fn bundled_inputs_are_found() {
+        let path: PathBuf = bundled_input_path(7);
+        assert!(path.ends_with("day07/input.txt"));
+        assert_eq!(fs::read_to_string(path).unwrap(), day07::Day07::INPUT);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::Day01;
+use aoc_common::{Generator, Rng};
+
+impl Generator for Day01 {
+    /// Makes up `size` frequency changes. Like the real input, each pass through them drifts a
+    /// little, so the frequency usually takes several passes to repeat.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut changes = (1..size.max(1))
+            .map(|_| signed(rng, 20_000))
+            .collect::<Vec<_>>();
+        let drift = signed(rng, 1000);
+        changes.push(drift - changes.iter().sum::<i64>());
+
+        changes
+            .iter()
+            .map(|change| format!("{:+}\n", change))
+            .collect()
+    }
+}
+
+/// A number up to `max` away from zero, but never zero itself.
+fn signed(rng: &mut Rng, max: i64) -> i64 {
+    let magnitude = rng.range(1..=max);
+    if rng.chance(1, 2) {
+        magnitude
+    } else {
+        -magnitude
+    }
+}
@@ -0,0 +1,340 @@
+use aoc_common::error::lines;
+use aoc_common::{NoConfig, ParseError, Solution};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+mod generate;
+pub mod stream;
+
+pub struct Day01;
+
+/// Where the frequency first reaches a value it has reached before, if it ever does.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Repetition {
+    Repeat {
+        frequency: isize,
+        /// How many changes had been made when the frequency repeated.
+        changes: u128,
+        /// The pass through the changes the repeat happened on, starting from 1.
+        pass: u128,
+        /// The index of the change that caused the repeat, starting from 0.
+        index: usize,
+    },
+    /// Every frequency is left behind by the drift of each pass before anything catches up with
+    /// it, so no frequency is ever reached twice.
+    NeverRepeats,
+}
+
+impl fmt::Display for Repetition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Repetition::Repeat { frequency, .. } => write!(f, "{}", frequency),
+            Repetition::NeverRepeats => write!(f, "never repeats"),
+        }
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Vec<isize>;
+    type Config = NoConfig;
+    type PartOne = isize;
+    type PartTwo = Repetition;
+
+    fn parse(input: &str) -> Result<Vec<isize>, ParseError> {
+        lines(Self::DAY, input)
+            .flat_map(|line| {
+                changes_on(line.text)
+                    .map(move |freq_change| line.parse(freq_change, "a frequency change"))
+            })
+            .collect()
+    }
+
+    fn part_one(freq_changes: &Vec<isize>, _: &NoConfig) -> Result<isize, Box<dyn Error>> {
+        resulting_frequency(freq_changes.iter().map(|change| Ok(*change)))
+    }
+
+    fn part_two(freq_changes: &Vec<isize>, _: &NoConfig) -> Result<Repetition, Box<dyn Error>> {
+        repetition(freq_changes.iter().map(|change| Ok(*change)))
+    }
+}
+
+/// The frequency changes written on a line, which can be separated by commas as well as whitespace
+/// (the puzzle's examples are written `+1, -2, +3`) and followed by a `#` comment.
+fn changes_on(text: &str) -> impl Iterator<Item = &str> {
+    let uncommented = text.find('#').map_or(text, |comment| &text[..comment]);
+    uncommented
+        .split(is_separator)
+        .filter(|freq_change| !freq_change.is_empty())
+}
+
+/// Whether `c` separates two changes. Only ASCII whitespace counts, so that a reader can pick
+/// changes out of its bytes without decoding them.
+fn is_separator(c: char) -> bool {
+    c.is_ascii_whitespace() || c == ','
+}
+
+/// Adds up the changes, which may come from a reader that fails part way through.
+fn resulting_frequency<I>(mut freq_changes: I) -> Result<isize, Box<dyn Error>>
+where
+    I: Iterator<Item = Result<isize, Box<dyn Error>>>,
+{
+    freq_changes.try_fold(0isize, |freq, change| {
+        freq.checked_add(change?).ok_or_else(|| OVERFLOW.into())
+    })
+}
+
+fn repetition<I>(freq_changes: I) -> Result<Repetition, Box<dyn Error>>
+where
+    I: Iterator<Item = Result<isize, Box<dyn Error>>>,
+{
+    let mut frequencies = Vec::with_capacity(freq_changes.size_hint().0);
+    let mut drift = 0isize;
+    for change in freq_changes {
+        frequencies.push(drift);
+        drift = drift.checked_add(change?).ok_or(OVERFLOW)?;
+    }
+    if frequencies.is_empty() {
+        return Err("There are no frequency changes to repeat.".into());
+    }
+
+    Ok(first_repeat(&frequencies, drift))
+}
+
+/// Finds the first frequency reached twice, given the frequencies reached during the first pass
+/// through the changes (starting from 0) and how far a whole pass drifts the frequency.
+///
+/// Rather than cycling through the changes until a frequency comes up again, which can take
+/// arbitrarily many passes, this works out when each frequency would be caught up with directly.
+fn first_repeat(frequencies: &[isize], drift: isize) -> Repetition {
+    let changes = frequencies.len() as u128;
+    let repeat = |reached: u128, frequency: isize| Repetition::Repeat {
+        frequency,
+        changes: reached,
+        pass: (reached - 1) / changes + 1,
+        index: ((reached - 1) % changes) as usize,
+    };
+
+    if drift == 0 {
+        // Every pass repeats the first, which ends back at 0.
+        let mut seen = HashSet::new();
+        let (reached, freq) = frequencies
+            .iter()
+            .chain(Some(&0))
+            .enumerate()
+            .find(|(_, freq)| !seen.insert(**freq))
+            .expect("the first pass ends where it started");
+        return repeat(reached as u128, *freq);
+    }
+
+    // Pass `k` reaches `frequencies[i] + k * drift` after `k * n + i` changes, so two frequencies
+    // can only meet if they are the same modulo the drift. Within those groups, a frequency is
+    // caught up with by the nearest frequency behind it, after however many passes it takes to
+    // cover the distance between them. Flipping the frequencies for a negative drift means
+    // "behind" is always "smaller".
+    let sign = drift.signum() as i128;
+    let drift = (drift as i128).abs();
+    let mut by_group = frequencies
+        .iter()
+        .enumerate()
+        .map(|(index, freq)| {
+            let freq = *freq as i128 * sign;
+            (freq.rem_euclid(drift), freq, index)
+        })
+        .collect::<Vec<_>>();
+    by_group.sort_unstable();
+
+    by_group
+        .windows(2)
+        .filter(|pair| pair[0].0 == pair[1].0)
+        .map(|pair| {
+            let ((_, behind, behind_index), (_, ahead, ahead_index)) = (pair[0], pair[1]);
+            // The same frequency twice in the first pass repeats as soon as it comes up again.
+            let passes = ((ahead - behind) / drift) as u128;
+            let reached = if passes == 0 {
+                ahead_index as u128
+            } else {
+                passes.saturating_mul(changes) + behind_index as u128
+            };
+            (reached, frequencies[ahead_index])
+        })
+        .min()
+        .map_or(Repetition::NeverRepeats, |(reached, freq)| {
+            repeat(reached, freq)
+        })
+}
+
+const OVERFLOW: &str = "The frequency overflowed.";
+
+#[cfg(test)]
+mod tests {
+    use super::{Day01, Repetition};
+    use aoc_common::{NoConfig, Solution};
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    fn part_one(changes: &str) -> isize {
+        Day01::part_one(&Day01::parse(changes).unwrap(), &NoConfig {}).unwrap()
+    }
+
+    fn part_two(changes: &str) -> isize {
+        match Day01::part_two(&Day01::parse(changes).unwrap(), &NoConfig {}).unwrap() {
+            Repetition::Repeat { frequency, .. } => frequency,
+            Repetition::NeverRepeats => panic!("{:?} never repeats", changes),
+        }
+    }
+
+    #[test]
+    fn resulting_frequency() {
+        assert_eq!(part_one("+1\n-2\n+3\n+1"), 3);
+        assert_eq!(part_one("+1\n+1\n+1"), 3);
+        assert_eq!(part_one("+1\n+1\n-2"), 0);
+        assert_eq!(part_one("-1\n-2\n-3"), -6);
+    }
+
+    #[test]
+    fn first_repeated_frequency() {
+        assert_eq!(part_two("+1\n-2\n+3\n+1"), 2);
+        assert_eq!(part_two("+1\n-1"), 0);
+        assert_eq!(part_two("+3\n+3\n+4\n-2\n-4"), 10);
+        assert_eq!(part_two("-6\n+3\n+8\n+5\n-6"), 5);
+        assert_eq!(part_two("+7\n+7\n-2\n-7\n-4"), 14);
+    }
+
+    #[test]
+    fn repeats_after_many_passes() {
+        // Cycling through these would take two trillion changes.
+        assert_eq!(part_two("+1000000000000\n-999999999999"), 1_000_000_000_000);
+        assert_eq!(
+            part_two("-1000000000000\n+999999999999"),
+            -1_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn where_the_repeat_happens() {
+        let changes = Day01::parse("+3\n+3\n+4\n-2\n-4").unwrap();
+        assert_eq!(
+            Day01::part_two(&changes, &NoConfig {}).unwrap(),
+            Repetition::Repeat {
+                frequency: 10,
+                changes: 7,
+                pass: 2,
+                index: 1,
+            }
+        );
+
+        let changes = Day01::parse("+1\n-1").unwrap();
+        let repetition = Day01::part_two(&changes, &NoConfig {}).unwrap();
+        assert_eq!(
+            repetition,
+            Repetition::Repeat {
+                frequency: 0,
+                changes: 2,
+                pass: 1,
+                index: 1,
+            }
+        );
+        assert_eq!(repetition.to_string(), "0");
+        assert_eq!(Repetition::NeverRepeats.to_string(), "never repeats");
+    }
+
+    /// Finds the first repeat the slow way, cycling through the changes for as many passes as it
+    /// could possibly take.
+    fn brute_force(changes: &[isize]) -> Option<(isize, u128)> {
+        let drift = changes.iter().sum::<isize>().abs();
+        let passes = if drift == 0 { 1 } else { 20 * 20 / drift + 1 };
+        let mut seen = HashSet::new();
+        seen.insert(0);
+        let mut freq = 0;
+        let cycled = changes.iter().cycle().take(changes.len() * passes as usize);
+        for (made, change) in cycled.enumerate() {
+            freq += change;
+            if !seen.insert(freq) {
+                return Some((freq, made as u128 + 1));
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn frequencies_that_never_repeat() {
+        for changes in &["+1\n+1", "+5\n-3\n+4"] {
+            let changes = Day01::parse(changes).unwrap();
+            assert_eq!(
+                Day01::part_two(&changes, &NoConfig {}).unwrap(),
+                Repetition::NeverRepeats
+            );
+        }
+        assert!(Day01::part_two(&Vec::new(), &NoConfig {}).is_err());
+    }
+
+    #[test]
+    fn overflowing_frequencies() {
+        let changes = vec![isize::MAX, 1];
+        assert!(Day01::part_one(&changes, &NoConfig {}).is_err());
+        assert!(Day01::part_two(&changes, &NoConfig {}).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn matches_cycling_through_the_changes(
+            changes in prop::collection::vec(-20isize..=20, 1..20),
+        ) {
+            let repeat = match Day01::part_two(&changes, &NoConfig {}).unwrap() {
+                Repetition::Repeat { frequency, changes: made, pass, index } => {
+                    prop_assert_eq!((pass - 1) * changes.len() as u128 + index as u128 + 1, made);
+                    Some((frequency, made))
+                }
+                Repetition::NeverRepeats => None,
+            };
+            prop_assert_eq!(repeat, brute_force(&changes));
+        }
+
+        #[test]
+        fn parses_any_changes(
+            changes in prop::collection::vec(-1_000_000isize..1_000_000, 0..50),
+        ) {
+            let input = changes
+                .iter()
+                .map(|change| format!("{:+}\n", change))
+                .collect::<String>();
+            prop_assert_eq!(Day01::parse(&input).unwrap(), changes);
+        }
+
+        #[test]
+        fn rejects_malformed_changes(
+            changes in prop::collection::vec(-1000isize..1000, 0..20),
+            index in any::<prop::sample::Index>(),
+            token in "[+-]?[a-z]+",
+        ) {
+            let mut lines = changes
+                .iter()
+                .map(|change| format!("{:+}", change))
+                .collect::<Vec<_>>();
+            let line = index.index(lines.len() + 1);
+            lines.insert(line, token.clone());
+
+            let err = Day01::parse(&lines.join("\n")).unwrap_err();
+            prop_assert_eq!((err.line, err.column), (line + 1, 1));
+            prop_assert_eq!(err.text, token);
+        }
+    }
+
+    #[test]
+    fn commas_and_comments() {
+        assert_eq!(Day01::parse("+1, -2, +3, +1").unwrap(), vec![1, -2, 3, 1]);
+        assert_eq!(
+            Day01::parse("# calibration\n+1 # first\n-2,+3,\n\n+1#last").unwrap(),
+            vec![1, -2, 3, 1]
+        );
+        assert_eq!(part_two("+3, +3, +4, -2, -4"), 10);
+
+        let err = Day01::parse("+1, -2\n+3; +1 # not +x").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "+3;");
+    }
+}
@@ -0,0 +1,246 @@
+//! Calibrating straight from a reader, for change lists too big to load into memory at once.
+
+use crate::{is_separator, Day01, Repetition};
+use aoc_common::{ParseError, Solution};
+use std::error::Error;
+use std::io::{self, BufRead};
+use std::str;
+
+/// The frequency changes in a reader, parsed one at a time as they're needed.
+///
+/// Changes are written the same as the input `Day01::parse` accepts, and bad changes are reported
+/// with their line and column. Only the change being parsed is kept, so even an input that's all
+/// on one line is never held in memory.
+pub struct Changes<R> {
+    reader: R,
+    change: Vec<u8>,
+    /// The line the next byte is on, starting from 1.
+    line: usize,
+    /// The column the next byte is at, in characters and starting from 1.
+    column: usize,
+    /// Whether the rest of the line is a comment.
+    comment: bool,
+}
+
+impl<R: BufRead> Changes<R> {
+    pub fn new(reader: R) -> Changes<R> {
+        Changes {
+            reader,
+            change: Vec::new(),
+            line: 1,
+            column: 1,
+            comment: false,
+        }
+    }
+
+    /// Reads the next change into `change`, returning the line and column it starts at, or `None`
+    /// once the input runs out.
+    fn read_change(&mut self) -> io::Result<Option<(usize, usize)>> {
+        self.change.clear();
+        let mut start = None;
+        loop {
+            let buf = match self.reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if buf.is_empty() {
+                return Ok(start);
+            }
+
+            let mut used = 0;
+            for &byte in buf {
+                let ends_change = byte == b'\n' || byte == b'#' || is_separator(char::from(byte));
+                if ends_change && !self.comment && start.is_some() {
+                    // The separator is left for the next change to skip over.
+                    self.reader.consume(used);
+                    return Ok(start);
+                }
+
+                used += 1;
+                if byte == b'\n' {
+                    self.line += 1;
+                    self.column = 1;
+                    self.comment = false;
+                    continue;
+                }
+                if !self.comment && !ends_change {
+                    start.get_or_insert((self.line, self.column));
+                    self.change.push(byte);
+                }
+                self.comment |= byte == b'#';
+                // Only the first byte of each UTF-8 character starts a new column.
+                if byte & 0xc0 != 0x80 {
+                    self.column += 1;
+                }
+            }
+            self.reader.consume(used);
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Changes<R> {
+    type Item = Result<isize, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (line, column) = match self.read_change() {
+            Ok(start) => start?,
+            Err(err) => return Some(Err(err.into())),
+        };
+        let change = str::from_utf8(&self.change)
+            .ok()
+            .and_then(|change| change.parse().ok())
+            .ok_or_else(|| {
+                let text = String::from_utf8_lossy(&self.change);
+                let message = "expected a frequency change";
+                ParseError::new(Day01::DAY, line, column, &text, message).into()
+            });
+        Some(change)
+    }
+}
+
+/// The frequency after every change in `reader`, the same as part one, using a change's worth of
+/// memory however long the input is.
+pub fn resulting_frequency<R: BufRead>(reader: R) -> Result<isize, Box<dyn Error>> {
+    crate::resulting_frequency(Changes::new(reader))
+}
+
+/// The first frequency reached twice, the same as part two.
+///
+/// Working out when frequencies repeat needs every frequency reached in the first pass, so this
+/// keeps one number per change, but never the text of the input.
+pub fn first_repeated_frequency<R: BufRead>(reader: R) -> Result<Repetition, Box<dyn Error>> {
+    crate::repetition(Changes::new(reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{first_repeated_frequency, resulting_frequency, Changes};
+    use crate::Day01;
+    use aoc_common::{NoConfig, Rng, Solution};
+    use proptest::prelude::*;
+    use std::io::{self, BufReader, Read};
+
+    #[test]
+    fn matches_solving_the_whole_input() {
+        let changes = Day01::parse(Day01::INPUT).unwrap();
+        assert_eq!(
+            resulting_frequency(Day01::INPUT.as_bytes()).unwrap(),
+            Day01::part_one(&changes, &NoConfig {}).unwrap()
+        );
+        assert_eq!(
+            first_repeated_frequency(Day01::INPUT.as_bytes()).unwrap(),
+            Day01::part_two(&changes, &NoConfig {}).unwrap()
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_change() {
+        let err = resulting_frequency("+1\n\n+2 x3\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 1, line 3, column 4: expected a frequency change (found `x3`)"
+        );
+        let err = resulting_frequency("+1, -2 # +3\n+2,x3".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 1, line 2, column 4: expected a frequency change (found `x3`)"
+        );
+
+        let overflowing = format!("+{}\n+1\n", isize::MAX);
+        assert!(resulting_frequency(overflowing.as_bytes()).is_err());
+        assert!(first_repeated_frequency(overflowing.as_bytes()).is_err());
+        assert!(first_repeated_frequency("".as_bytes()).is_err());
+    }
+
+    /// A reader that fails after giving `good` bytes.
+    struct Failing<'a> {
+        good: &'a [u8],
+    }
+
+    impl Read for Failing<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.good.is_empty() {
+                Err(io::Error::other("the disk caught fire"))
+            } else {
+                self.good.read(buf)
+            }
+        }
+    }
+
+    #[test]
+    fn read_errors_are_reported() {
+        let reader = BufReader::new(Failing { good: b"+1\n-2\n" });
+        let err = first_repeated_frequency(reader).unwrap_err();
+        assert_eq!(err.to_string(), "the disk caught fire");
+    }
+
+    /// Makes up changes as they're read, so the input never exists all at once.
+    struct Generated {
+        rng: Rng,
+        remaining: usize,
+        drift: i64,
+        /// The part of the last change that didn't fit in the caller's buffer.
+        unread: Vec<u8>,
+    }
+
+    impl Read for Generated {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.unread.is_empty() && self.remaining > 0 {
+                self.remaining -= 1;
+                let change = self.rng.range(-1000..=1000);
+                self.drift += change;
+                self.unread = format!("{:+}\n", change).into_bytes();
+            }
+            let read = self.unread.len().min(buf.len());
+            buf[..read].copy_from_slice(&self.unread[..read]);
+            self.unread.drain(..read);
+            Ok(read)
+        }
+    }
+
+    #[test]
+    fn large_inputs() {
+        let mut generated = Generated {
+            rng: Rng::new(1),
+            remaining: 1_000_000,
+            drift: 0,
+            unread: Vec::new(),
+        };
+        let frequency = resulting_frequency(BufReader::new(&mut generated)).unwrap();
+        assert_eq!(frequency as i64, generated.drift);
+    }
+
+    #[test]
+    fn one_change_at_a_time() {
+        let input = vec!["+1, -2"; 100_000].join(", ");
+        let mut changes = Changes::new(input.as_bytes());
+        assert_eq!(changes.by_ref().count(), 200_000);
+        assert!(changes.change.capacity() < 100);
+
+        let err = resulting_frequency("+1, é, x".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 1, line 1, column 5: expected a frequency change (found `é`)"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn matches_parsing(
+            changes in prop::collection::vec((-1000isize..1000, "[ \t\n,]{1,3}|#[^\n]*\n"), 1..50),
+        ) {
+            let input = changes
+                .iter()
+                .map(|(change, space)| format!("{:+}{}", change, space))
+                .collect::<String>();
+            let parsed = Changes::new(input.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+            prop_assert_eq!(&parsed, &Day01::parse(&input).unwrap());
+
+            prop_assert_eq!(
+                first_repeated_frequency(input.as_bytes()).unwrap(),
+                Day01::part_two(&parsed, &NoConfig {}).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::Day02;
+use aoc_common::{Generator, Rng};
+
+const ID_LENGTH: usize = 26;
+
+impl Generator for Day02 {
+    /// Makes up `size` random box IDs, two of which differ by a single letter.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut ids = (1..size.max(2))
+            .map(|_| (0..ID_LENGTH).map(|_| letter(rng)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let mut twin = rng.choose(&ids).clone();
+        let position = rng.index(ID_LENGTH);
+        let shift = rng.range(1..=25) as u8;
+        twin[position] = (b'a' + (twin[position] as u8 - b'a' + shift) % 26) as char;
+        ids.push(twin);
+        rng.shuffle(&mut ids);
+
+        ids.iter()
+            .map(|id| id.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+fn letter(rng: &mut Rng) -> char {
+    (b'a' + rng.below(26) as u8) as char
+}
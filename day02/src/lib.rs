@@ -0,0 +1,300 @@
+use aoc_common::error::lines;
+use aoc_common::{NoConfig, ParseError, Solution};
+use std::collections::HashMap;
+use std::error::Error;
+use std::iter;
+
+mod generate;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Vec<String>;
+    type Config = NoConfig;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        lines(Self::DAY, input)
+            .map(|line| {
+                let id = line.text.trim();
+                match id.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+                    Some((index, c)) => Err(line.error(
+                        &id[index..index + c.len_utf8()],
+                        "expected a lowercase letter",
+                    )),
+                    None => Ok(id.to_owned()),
+                }
+            })
+            .collect()
+    }
+
+    fn part_one(ids: &Vec<String>, _: &NoConfig) -> Result<usize, Box<dyn Error>> {
+        Ok(compute_checksum(ids))
+    }
+
+    fn part_two(ids: &Vec<String>, _: &NoConfig) -> Result<String, Box<dyn Error>> {
+        near_duplicates(ids)
+            .first()
+            .map(|pair| common_letters(ids, pair))
+            .ok_or_else(|| "No two IDs differ by exactly one letter.".into())
+    }
+}
+
+fn compute_checksum(ids: &[String]) -> usize {
+    let (twos, threes) = ids
+        .iter()
+        .map(|id| compute_letter_freq(id))
+        .fold((0, 0), |acc, (twos, threes)| (acc.0 + twos, acc.1 + threes));
+
+    twos * threes
+}
+
+fn compute_letter_freq(input: &str) -> (usize, usize) {
+    let mut frequencies = HashMap::new();
+    for c in input.chars() {
+        frequencies.entry(c).and_modify(|c| *c += 1).or_insert(1);
+    }
+
+    let (mut twos, mut threes) = (0, 0);
+    for (_, count) in frequencies {
+        if count == 3 {
+            threes = 1;
+        } else if count == 2 {
+            twos = 1;
+        }
+    }
+
+    (twos, threes)
+}
+
+/// Two IDs, by their index, that are the same apart from the letter at `position`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct NearDuplicate {
+    first: usize,
+    second: usize,
+    position: usize,
+}
+
+/// The letters the two IDs have in common, which is either of them without the differing letter.
+fn common_letters(ids: &[String], pair: &NearDuplicate) -> String {
+    let id = &ids[pair.first];
+    format!("{}{}", &id[..pair.position], &id[pair.position + 1..])
+}
+
+/// Any odd number works, this one is FNV's prime.
+const BASE: u64 = 0x0100_0000_01b3;
+
+/// Finds every pair of IDs that differ in exactly one position, ordered by the first ID of the pair
+/// and then the second. IDs are compared byte by byte, which is the same as letter by letter for
+/// the lowercase IDs the input is made of.
+///
+/// Rather than comparing every pair of IDs, each distinct ID is hashed once per position with that
+/// position left out, and only IDs with the same hash for the same position are compared. Leaving
+/// a byte out of a polynomial hash takes a single subtraction, and distinct IDs that match apart
+/// from one position must differ there, so this takes time in proportion to the size of the input
+/// plus the pairs found.
+fn near_duplicates(ids: &[String]) -> Vec<NearDuplicate> {
+    // Copies of an ID are only looked for once, then paired up with everything the ID is.
+    let mut copies: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, id) in ids.iter().enumerate() {
+        copies.entry(id).or_default().push(index);
+    }
+    let distinct = copies.into_iter().collect::<Vec<_>>();
+
+    let longest = ids.iter().map(String::len).max().unwrap_or(0);
+    let powers = iter::successors(Some(1u64), |power| Some(power.wrapping_mul(BASE)))
+        .take(longest)
+        .collect::<Vec<_>>();
+    let hashes = distinct
+        .iter()
+        .map(|(id, _)| {
+            id.bytes().zip(&powers).fold(0u64, |hash, (byte, power)| {
+                hash.wrapping_add(u64::from(byte).wrapping_mul(*power))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut pairs = Vec::new();
+    // The first ID with each hash, and every later ID along with the first ID it has the same hash
+    // as. Nearly every hash is only seen once, so only the later IDs need to be kept in order.
+    let mut first_with: HashMap<(usize, u64), usize> = HashMap::with_capacity(distinct.len());
+    let mut same_as = Vec::new();
+    for (position, power) in powers.iter().enumerate() {
+        first_with.clear();
+        same_as.clear();
+        for (index, ((id, _), hash)) in distinct.iter().zip(&hashes).enumerate() {
+            if let Some(byte) = id.as_bytes().get(position) {
+                let masked = hash.wrapping_sub(u64::from(*byte).wrapping_mul(*power));
+                let first = *first_with.entry((id.len(), masked)).or_insert(index);
+                if first != index {
+                    same_as.push((first, index));
+                }
+            }
+        }
+        same_as.sort_unstable();
+
+        for bucket in same_as.chunk_by(|a, b| a.0 == b.0) {
+            let bucket = iter::once(bucket[0].0)
+                .chain(bucket.iter().map(|(_, index)| *index))
+                .collect::<Vec<_>>();
+            for (offset, &one) in bucket.iter().enumerate() {
+                for &other in &bucket[offset + 1..] {
+                    let ((id, id_copies), (other_id, other_copies)) =
+                        (&distinct[one], &distinct[other]);
+                    // Different IDs can still hash the same, so check they really do match.
+                    if !differ_only_at(id, other_id, position) {
+                        continue;
+                    }
+                    for &a in id_copies {
+                        for &b in other_copies {
+                            pairs.push(NearDuplicate {
+                                first: a.min(b),
+                                second: a.max(b),
+                                position,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
+
+fn differ_only_at(id: &str, other_id: &str, position: usize) -> bool {
+    let (id, other_id) = (id.as_bytes(), other_id.as_bytes());
+    id.len() == other_id.len()
+        && id[position] != other_id[position]
+        && id[..position] == other_id[..position]
+        && id[position + 1..] == other_id[position + 1..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_checksum, compute_letter_freq, near_duplicates, Day02, NearDuplicate};
+    use aoc_common::Solution;
+    use proptest::prelude::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn letter_frequencies() {
+        assert_eq!(compute_letter_freq("abcdef"), (0, 0));
+        assert_eq!(compute_letter_freq("bababc"), (1, 1));
+        assert_eq!(compute_letter_freq("abbcde"), (1, 0));
+        assert_eq!(compute_letter_freq("abcccd"), (0, 1));
+        assert_eq!(compute_letter_freq("aabcdd"), (1, 0));
+        assert_eq!(compute_letter_freq("abcdee"), (1, 0));
+        assert_eq!(compute_letter_freq("ababab"), (0, 1));
+    }
+
+    #[test]
+    fn checksum() {
+        let ids = ids(&[
+            "abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab",
+        ]);
+        assert_eq!(compute_checksum(&ids), 12);
+    }
+
+    #[test]
+    fn common_letters() {
+        let ids = ids(&[
+            "abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz",
+        ]);
+        let pairs = near_duplicates(&ids);
+        assert_eq!(
+            pairs,
+            vec![NearDuplicate {
+                first: 1,
+                second: 4,
+                position: 2,
+            }]
+        );
+        assert_eq!(super::common_letters(&ids, &pairs[0]), "fgij");
+    }
+
+    #[test]
+    fn every_near_duplicate() {
+        let ids = ids(&["abca", "abcb", "xbca", "abca", "abc", "abcc"]);
+        let pairs = near_duplicates(&ids)
+            .iter()
+            .map(|pair| (pair.first, pair.second, pair.position))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            vec![
+                (0, 1, 3),
+                (0, 2, 0),
+                (0, 5, 3),
+                (1, 3, 3),
+                (1, 5, 3),
+                (2, 3, 0),
+                (3, 5, 3),
+            ]
+        );
+        // A letter that's repeated elsewhere in the ID is only left out where it differs.
+        assert_eq!(
+            super::common_letters(&ids, &near_duplicates(&ids)[0]),
+            "abc"
+        );
+    }
+
+    /// Finds the near duplicates the slow way, comparing every pair of IDs.
+    fn brute_force(ids: &[String]) -> Vec<(usize, usize, usize)> {
+        let mut pairs = Vec::new();
+        for (first, id) in ids.iter().enumerate() {
+            for (second, other_id) in ids.iter().enumerate().skip(first + 1) {
+                if id.len() != other_id.len() {
+                    continue;
+                }
+                let differences = id
+                    .bytes()
+                    .zip(other_id.bytes())
+                    .enumerate()
+                    .filter(|(_, (a, b))| a != b)
+                    .map(|(position, _)| position)
+                    .collect::<Vec<_>>();
+                if let [position] = differences[..] {
+                    pairs.push((first, second, position));
+                }
+            }
+        }
+        pairs
+    }
+
+    proptest! {
+        #[test]
+        fn matches_comparing_every_pair(ids in prop::collection::vec("[ab]{0,4}", 0..40)) {
+            let pairs = near_duplicates(&ids)
+                .iter()
+                .map(|pair| (pair.first, pair.second, pair.position))
+                .collect::<Vec<_>>();
+            prop_assert_eq!(pairs, brute_force(&ids));
+        }
+
+        #[test]
+        fn parses_any_ids(ids in prop::collection::vec("[a-z]{1,30}", 0..50)) {
+            prop_assert_eq!(Day02::parse(&ids.join("\n")).unwrap(), ids);
+        }
+
+        #[test]
+        fn rejects_other_characters(
+            before in "[a-z]{0,10}",
+            bad in "[A-Z0-9_#]",
+            after in "[a-z]{0,10}",
+        ) {
+            let id = format!("{}{}{}", before, bad, after);
+            let err = Day02::parse(&format!("abcde\n{}", id)).unwrap_err();
+            prop_assert_eq!((err.line, err.column), (2, before.len() + 1));
+            prop_assert_eq!(err.text, bad);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Day03;
+use aoc_common::{Generator, Rng};
+
+/// The largest a claim can be in either direction.
+const MAX_SIZE: i64 = 29;
+
+impl Generator for Day03 {
+    /// Makes up `size` claims, spread over fabric that grows with the number of claims so they
+    /// overlap about as much as the real input's do. One claim is kept clear of all the others.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let count = size.max(1);
+        let side = ((count as f64 / 1300.0).sqrt() * 1000.0).max(100.0) as i64;
+        let lone = rng.index(count);
+
+        (0..count)
+            .map(|index| {
+                // Every other claim ends before `side + MAX_SIZE`, so this one can't touch them.
+                let x = if index == lone {
+                    side + MAX_SIZE
+                } else {
+                    rng.range(0..=side)
+                };
+                let y = rng.range(0..=side);
+                let width = rng.range(1..=MAX_SIZE);
+                let height = rng.range(1..=MAX_SIZE);
+                format!("#{} @ {},{}: {}x{}\n", index + 1, x, y, width, height)
+            })
+            .collect()
+    }
+}
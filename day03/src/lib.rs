@@ -0,0 +1,250 @@
+use aoc_common::error::lines;
+use aoc_common::{Grid, Line, NoConfig, ParseError, Point, Rect, Solution};
+use std::collections::HashSet;
+use std::error::Error;
+
+mod generate;
+mod render;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Claim {
+    id: usize,
+    area: Rect,
+}
+
+impl Claim {
+    fn is_overlapping(&self, other: &Claim) -> bool {
+        self.area.intersection(&other.area).is_some()
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Vec<Claim>;
+    type Config = NoConfig;
+    type PartOne = u64;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Claim>, ParseError> {
+        parse_claims(input)
+    }
+
+    fn part_one(claims: &Vec<Claim>, _: &NoConfig) -> Result<u64, Box<dyn Error>> {
+        compute_overlap(claims).ok_or_else(|| "The overlapping area is too large.".into())
+    }
+
+    fn part_two(claims: &Vec<Claim>, _: &NoConfig) -> Result<usize, Box<dyn Error>> {
+        match find_best_claim(claims) {
+            Some(claim) => Ok(claim.id),
+            None => Err("Every claim overlaps another claim.".into()),
+        }
+    }
+}
+
+/// Counts the square inches of fabric within two or more claims, or `None` if there are too many
+/// to count.
+///
+/// The edges of the claims cut the fabric into cells that are each covered by the same claims
+/// throughout, so only the cells need counting rather than every square inch.
+fn compute_overlap(claims: &[Claim]) -> Option<u64> {
+    let xs = edges(claims, |point| point.x);
+    let ys = edges(claims, |point| point.y);
+    let cell = |edges: &[i64], edge: i64| {
+        edges
+            .binary_search(&edge)
+            .expect("every claim edge is in the list of edges")
+    };
+
+    // Mark the corners of each claim, so that summing the marks above and to the left of a cell
+    // gives how many claims cover it.
+    let mut coverage = Grid::new(xs.len(), ys.len(), 0i32);
+    for claim in claims {
+        let (left, right) = (cell(&xs, claim.area.min.x), cell(&xs, claim.area.max.x + 1));
+        let (top, bottom) = (cell(&ys, claim.area.min.y), cell(&ys, claim.area.max.y + 1));
+        coverage[(left, top)] += 1;
+        coverage[(right, top)] -= 1;
+        coverage[(left, bottom)] -= 1;
+        coverage[(right, bottom)] += 1;
+    }
+    for y in 0..ys.len() {
+        for x in 1..xs.len() {
+            coverage[(x, y)] += coverage[(x - 1, y)];
+        }
+    }
+    for y in 1..ys.len() {
+        for x in 0..xs.len() {
+            coverage[(x, y)] += coverage[(x, y - 1)];
+        }
+    }
+
+    let overlap = coverage.iter().filter(|(_, claims)| **claims > 1).try_fold(
+        0u64,
+        |overlap, ((x, y), _)| {
+            let width = (xs[x + 1] - xs[x]) as u64;
+            let height = (ys[y + 1] - ys[y]) as u64;
+            overlap.checked_add(width.checked_mul(height)?)
+        },
+    );
+    overlap
+}
+
+/// The sorted positions along one axis where a claim starts or ends.
+fn edges(claims: &[Claim], axis: fn(Point) -> i64) -> Vec<i64> {
+    let mut edges = claims
+        .iter()
+        .flat_map(|claim| vec![axis(claim.area.min), axis(claim.area.max) + 1])
+        .collect::<Vec<_>>();
+    edges.sort_unstable();
+    edges.dedup();
+    edges
+}
+
+fn find_best_claim(claims: &[Claim]) -> Option<&Claim> {
+    let mut overlapping = HashSet::new();
+    for (index, claim) in claims.iter().enumerate() {
+        for other_claim in &claims[index + 1..] {
+            if claim.is_overlapping(other_claim) {
+                overlapping.insert(claim.id);
+                overlapping.insert(other_claim.id);
+            }
+        }
+    }
+
+    claims.iter().find(|claim| !overlapping.contains(&claim.id))
+}
+
+fn parse_claims(input: &str) -> Result<Vec<Claim>, ParseError> {
+    lines(Day03::DAY, input).map(parse_claim).collect()
+}
+
+fn parse_claim(line: Line) -> Result<Claim, ParseError> {
+    let (id, rest) = line.split_once(line.text, "@")?;
+    let id = id.trim();
+    let id = match id.strip_prefix('#') {
+        Some(id) => line.parse(id, "a claim ID")?,
+        None => return Err(line.error(id, "expected `#` before the claim ID")),
+    };
+
+    let (position, size) = line.split_once(rest, ":")?;
+    let (x, y) = line.split_once(position.trim(), ",")?;
+    let x = line.parse::<u32>(x.trim(), "an x coordinate")?;
+    let y = line.parse::<u32>(y.trim(), "a y coordinate")?;
+
+    let size = size.trim();
+    let (width, height) = match line.split_once(size, "x")? {
+        // A width with an `x` of its own is cut off at the last `x` instead, so all of it is
+        // reported.
+        (width, _) if width.trim().parse::<u32>().is_err() => {
+            size.rsplit_once('x').expect("the size has an `x`")
+        }
+        split => split,
+    };
+    let width = line.parse::<u32>(width.trim(), "a width")?;
+    let height = line.parse::<u32>(height.trim(), "a height")?;
+
+    let corner = Point::new(i64::from(x), i64::from(y));
+    match Rect::with_size(corner, i64::from(width), i64::from(height)) {
+        Some(area) => Ok(Claim { id, area }),
+        None => Err(line.error(size, "expected a claim with a non-zero size")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse() {
+        use super::{parse_claim, Claim};
+        use aoc_common::{Line, Point, Rect};
+
+        let claim = parse_claim(Line::new(3, 1, "#1 @ 2,3: 4x5"));
+        assert_eq!(
+            claim,
+            Ok(Claim {
+                id: 1,
+                area: Rect::new(Point::new(2, 3), Point::new(5, 7))
+            })
+        )
+    }
+
+    #[test]
+    fn test_parse_error() {
+        use super::parse_claim;
+        use aoc_common::{Line, ParseError};
+
+        let err = parse_claim(Line::new(3, 4, "#1 @ 2,three: 4x5"));
+        assert_eq!(
+            err,
+            Err(ParseError::new(3, 4, 8, "three", "expected a y coordinate"))
+        );
+
+        let err = parse_claim(Line::new(3, 4, "#1 @ 2,3: xx5"));
+        assert_eq!(err, Err(ParseError::new(3, 4, 11, "x", "expected a width")));
+        let err = parse_claim(Line::new(3, 4, "#1 @ 2,3: 4x5x6"));
+        assert_eq!(
+            err,
+            Err(ParseError::new(3, 4, 13, "5x6", "expected a height"))
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        use super::{compute_overlap, find_best_claim, parse_claims};
+
+        let claims = parse_claims("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2").unwrap();
+        assert_eq!(compute_overlap(&claims), Some(4));
+        assert_eq!(find_best_claim(&claims).map(|claim| claim.id), Some(3));
+    }
+
+    #[test]
+    fn test_huge_claims() {
+        use super::{compute_overlap, parse_claims};
+
+        let claim = "@ 0,0: 4294967295x4294967295\n";
+        let claims = parse_claims(&format!("#1 {}#2 {}", claim, claim)).unwrap();
+        assert_eq!(compute_overlap(&claims), Some(4294967295 * 4294967295));
+
+        let beside = "@ 4294967295,0: 4294967295x4294967295\n";
+        let input = format!("#1 {}#2 {}#3 {}#4 {}", claim, claim, beside, beside);
+        assert_eq!(compute_overlap(&parse_claims(&input).unwrap()), None);
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_claim(
+            id in 1usize..100_000,
+            x in 0i64..1000,
+            y in 0i64..1000,
+            width in 1i64..100,
+            height in 1i64..100,
+        ) {
+            use super::{parse_claim, Claim};
+            use aoc_common::{Line, Point, Rect};
+
+            let text = format!("#{} @ {},{}: {}x{}", id, x, y, width, height);
+            let area = Rect::with_size(Point::new(x, y), width, height).unwrap();
+            prop_assert_eq!(parse_claim(Line::new(3, 1, &text)), Ok(Claim { id, area }));
+        }
+
+        #[test]
+        fn rejects_non_numeric_fields(field in 0usize..5, bad in "[a-z]{1,5}|-[0-9]{1,3}") {
+            use super::parse_claim;
+            use aoc_common::Line;
+
+            let mut fields = ["1", "2", "3", "4", "5"].map(String::from);
+            fields[field] = bad.clone();
+            let text = format!(
+                "#{} @ {},{}: {}x{}",
+                fields[0], fields[1], fields[2], fields[3], fields[4]
+            );
+
+            let err = parse_claim(Line::new(3, 1, &text)).unwrap_err();
+            prop_assert_eq!(err.text, bad);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{find_best_claim, Claim, Day03};
+use aoc_common::{Grid, Image, NoConfig, Render, Rgb};
+use std::error::Error;
+
+const FABRIC: Rgb = Rgb::new(24, 24, 32);
+const CLAIMED: Rgb = Rgb::new(70, 110, 200);
+const OVERLAPPING: Rgb = Rgb::new(230, 60, 50);
+const INTACT: Rgb = Rgb::new(80, 220, 90);
+
+impl Render for Day03 {
+    /// Draws the fabric with a pixel per square inch. Inches in a single claim are blue, turning
+    /// red as more claims overlap them, and the claim that overlaps nothing is green.
+    fn render(claims: &Vec<Claim>, _: &NoConfig) -> Result<Image, Box<dyn Error>> {
+        let bounds = claims
+            .iter()
+            .map(|claim| claim.area)
+            .reduce(|a, b| a.union(&b))
+            .ok_or("There are no claims to draw.")?;
+
+        // The fabric starts at the origin, so the image only needs to reach the bottom right claim.
+        let (width, height) = (bounds.max.x as usize + 1, bounds.max.y as usize + 1);
+        let mut image = Image::new(width, height, FABRIC)?;
+        let mut coverage = Grid::new(width, height, 0u32);
+        for section in claims.iter().flat_map(|claim| claim.area.points()) {
+            coverage[section] += 1;
+        }
+
+        for (position, claims) in coverage.iter() {
+            let colour = match claims {
+                0 => continue,
+                1 => CLAIMED,
+                claims => CLAIMED.mix(OVERLAPPING, f64::from(claims - 1) / 3.0),
+            };
+            image.set(position.into(), colour);
+        }
+        if let Some(claim) = find_best_claim(claims) {
+            for section in claim.area.points() {
+                image.set(section, INTACT);
+            }
+        }
+
+        Ok(image)
+    }
+}
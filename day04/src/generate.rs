@@ -0,0 +1,57 @@
+use crate::Day04;
+use aoc_common::{Generator, Rng};
+use chrono::prelude::*;
+
+impl Generator for Day04 {
+    /// Makes up `size` shifts on consecutive nights, shared between a pool of guards, each with a
+    /// few naps. The entries are shuffled like the real input's.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let guards = (0..(size / 20).max(1))
+            .map(|_| rng.range(1..=4000))
+            .collect::<Vec<_>>();
+        let mut night = NaiveDate::from_ymd_opt(1518, 1, 1).expect("the first night is valid");
+
+        let mut entries = Vec::new();
+        for _ in 0..size.max(1) {
+            let start = if rng.chance(1, 2) {
+                let evening = night
+                    .pred_opt()
+                    .expect("nights are after the earliest date");
+                at(evening, 23, rng.range(45..=59))
+            } else {
+                at(night, 0, rng.range(0..=5))
+            };
+            entries.push((
+                start,
+                format!("Guard #{} begins shift", rng.choose(&guards)),
+            ));
+
+            let mut minute = rng.range(0..=10);
+            while rng.chance(2, 3) {
+                let asleep = minute + rng.range(1..=20);
+                if asleep > 57 {
+                    break;
+                }
+                let awake = asleep + rng.range(1..=(59 - asleep).min(30));
+                entries.push((at(night, 0, asleep), "falls asleep".to_string()));
+                entries.push((at(night, 0, awake), "wakes up".to_string()));
+                minute = awake;
+            }
+
+            night = night
+                .succ_opt()
+                .expect("the nights stay within the calendar");
+        }
+        rng.shuffle(&mut entries);
+
+        entries
+            .iter()
+            .map(|(time, event)| format!("[{}] {}\n", time.format("%Y-%m-%d %H:%M"), event))
+            .collect()
+    }
+}
+
+fn at(date: NaiveDate, hour: u32, minute: i64) -> NaiveDateTime {
+    date.and_hms_opt(hour, minute as u32, 0)
+        .expect("generated times are valid")
+}
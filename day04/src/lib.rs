@@ -0,0 +1,252 @@
+use aoc_common::error::lines;
+use aoc_common::{Line, NoConfig, ParseError, Solution};
+use chrono::prelude::*;
+use std::collections::HashMap;
+use std::error::Error;
+
+mod generate;
+
+#[derive(Debug, PartialEq)]
+struct Entry {
+    date_time: NaiveDateTime,
+    event: Event,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Event {
+    BeginsShift(usize),
+    FallsAsleep,
+    WakesUp,
+}
+
+impl Entry {
+    fn new(line: Line) -> Result<Entry, ParseError> {
+        let (date_time, event) = line.split_once(line.text, "]")?;
+        let date_time = match date_time.trim_start().strip_prefix('[') {
+            Some(date_time) => NaiveDateTime::parse_from_str(date_time, "%Y-%m-%d %H:%M")
+                .map_err(|_| line.error(date_time, "expected a `YYYY-MM-DD hh:mm` timestamp"))?,
+            None => return Err(line.error(date_time, "expected `[` before the timestamp")),
+        };
+
+        let event = match event.trim() {
+            "falls asleep" => Event::FallsAsleep,
+            "wakes up" => Event::WakesUp,
+            event => {
+                let id = event
+                    .strip_prefix("Guard #")
+                    .and_then(|rest| rest.strip_suffix(" begins shift"))
+                    .ok_or_else(|| {
+                        line.error(
+                            event,
+                            "expected `falls asleep`, `wakes up` or `Guard #N begins shift`",
+                        )
+                    })?;
+                Event::BeginsShift(line.parse(id, "a guard ID")?)
+            }
+        };
+
+        Ok(Entry { date_time, event })
+    }
+}
+
+/// Sleep records for each guard, by guard ID.
+pub type Guards = HashMap<usize, GuardEntry>;
+
+pub struct GuardEntry {
+    minutes_asleep: [usize; 60],
+}
+
+impl GuardEntry {
+    fn new() -> GuardEntry {
+        GuardEntry {
+            minutes_asleep: [0; 60],
+        }
+    }
+
+    fn minutes_asleep(&self) -> usize {
+        self.minutes_asleep.iter().sum()
+    }
+
+    fn best_minute(&self) -> (usize, usize) {
+        self.minutes_asleep
+            .iter()
+            .cloned()
+            .enumerate()
+            .max_by_key(|(_, count)| *count)
+            .unwrap()
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Guards;
+    type Config = NoConfig;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Guards, ParseError> {
+        track_guards(input)
+    }
+
+    /// Finds the guard who sleeps the most, and the minute they are most often asleep.
+    fn part_one(guards: &Guards, _: &NoConfig) -> Result<usize, Box<dyn Error>> {
+        let best_guard = guards
+            .iter()
+            .max_by_key(|(_, guard)| guard.minutes_asleep());
+        strategy_result(best_guard)
+    }
+
+    /// Finds the guard who is most frequently asleep on the same minute.
+    fn part_two(guards: &Guards, _: &NoConfig) -> Result<usize, Box<dyn Error>> {
+        let best_guard = guards.iter().max_by_key(|(_, guard)| guard.best_minute().1);
+        strategy_result(best_guard)
+    }
+}
+
+fn strategy_result(best_guard: Option<(&usize, &GuardEntry)>) -> Result<usize, Box<dyn Error>> {
+    let (id, guard) = best_guard.ok_or("No guards were on duty.")?;
+    id.checked_mul(guard.best_minute().0)
+        .ok_or_else(|| format!("Guard #{} has too large an ID.", id).into())
+}
+
+fn track_guards(input: &str) -> Result<Guards, ParseError> {
+    let mut entries = lines(Day04::DAY, input)
+        .map(Entry::new)
+        .collect::<Result<Vec<Entry>, _>>()?;
+
+    entries.sort_by_key(|entry| entry.date_time);
+    let mut guards = HashMap::new();
+
+    let mut current_guard = None;
+    let mut asleep_at = None;
+    for entry in entries {
+        match entry.event {
+            Event::BeginsShift(guard) => {
+                current_guard = Some(guards.entry(guard).or_insert_with(GuardEntry::new));
+            }
+            Event::FallsAsleep => {
+                asleep_at = Some(entry.date_time.time());
+            }
+            Event::WakesUp => {
+                if let Some(asleep_at) = asleep_at {
+                    if let Some(current_guard) = &mut current_guard {
+                        let current_time = entry.date_time.time();
+                        for i in asleep_at.minute()..current_time.minute() {
+                            current_guard.minutes_asleep[i as usize] += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(guards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day04, Entry, Event};
+    use aoc_common::{Line, NoConfig, Solution};
+    use chrono::NaiveDate;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+
+    #[test]
+    fn most_minutes_asleep() {
+        let guards = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(guards[&10].minutes_asleep(), 50);
+        assert_eq!(guards[&10].best_minute().0, 24);
+        assert_eq!(Day04::part_one(&guards, &NoConfig {}).unwrap(), 240);
+    }
+
+    #[test]
+    fn most_frequently_asleep_minute() {
+        let guards = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(guards[&99].best_minute(), (45, 3));
+        assert_eq!(Day04::part_two(&guards, &NoConfig {}).unwrap(), 4455);
+    }
+
+    #[test]
+    fn huge_guard_ids() {
+        let guards = Day04::parse(&EXAMPLE.replace("#99", &format!("#{}", usize::MAX))).unwrap();
+        assert!(Day04::part_two(&guards, &NoConfig {}).is_err());
+    }
+
+    #[test]
+    fn entries_are_sorted_by_time() {
+        let mut shuffled = EXAMPLE.lines().collect::<Vec<_>>();
+        shuffled.reverse();
+        let guards = Day04::parse(&shuffled.join("\n")).unwrap();
+        assert_eq!(Day04::part_one(&guards, &NoConfig {}).unwrap(), 240);
+    }
+
+    fn event() -> impl Strategy<Value = Event> {
+        prop_oneof![
+            (0usize..10_000).prop_map(Event::BeginsShift),
+            Just(Event::FallsAsleep),
+            Just(Event::WakesUp),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_entry(
+            (month, day, hour, minute) in (1u32..=12, 1u32..=28, 0u32..24, 0u32..60),
+            event in event(),
+        ) {
+            let date_time = NaiveDate::from_ymd_opt(1518, month, day)
+                .and_then(|date| date.and_hms_opt(hour, minute, 0))
+                .unwrap();
+            let text = match event {
+                Event::BeginsShift(id) => format!("Guard #{} begins shift", id),
+                Event::FallsAsleep => "falls asleep".to_string(),
+                Event::WakesUp => "wakes up".to_string(),
+            };
+            let text = format!("[{}] {}", date_time.format("%Y-%m-%d %H:%M"), text);
+
+            prop_assert_eq!(
+                Entry::new(Line::new(4, 1, &text)).unwrap(),
+                Entry { date_time, event }
+            );
+        }
+
+        #[test]
+        fn rejects_unknown_events(event in "[a-z ]{1,20}") {
+            prop_assume!(!["falls asleep", "wakes up"].contains(&event.trim()));
+
+            let text = format!("[1518-11-01 00:00] {}", event);
+            let err = Entry::new(Line::new(4, 1, &text)).unwrap_err();
+            prop_assert_eq!(err.text, event.trim());
+        }
+
+        #[test]
+        fn rejects_invalid_timestamps(month in 13u32..100, day in 1u32..=28) {
+            let timestamp = format!("1518-{:02}-{:02} 00:00", month, day);
+            let text = format!("[{}] wakes up", timestamp);
+            let err = Entry::new(Line::new(4, 1, &text)).unwrap_err();
+            prop_assert_eq!((err.column, err.text), (2, timestamp));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::Day05;
+use aoc_common::{Generator, Rng};
+
+impl Generator for Day05 {
+    /// Makes up a polymer of `size` units. Units are often followed by the other polarity of an
+    /// earlier unit that hasn't been matched yet, so whole runs of the polymer react away.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut polymer = String::with_capacity(size);
+        let mut unmatched = Vec::new();
+        for _ in 0..size.max(1) {
+            match unmatched.pop() {
+                Some(unit) if rng.chance(1, 3) => polymer.push(flip(unit)),
+                previous => {
+                    unmatched.extend(previous);
+                    let unit = (b'a' + rng.below(26) as u8) as char;
+                    let unit = if rng.chance(1, 2) {
+                        unit.to_ascii_uppercase()
+                    } else {
+                        unit
+                    };
+                    polymer.push(unit);
+                    unmatched.push(unit);
+                }
+            }
+        }
+        polymer + "\n"
+    }
+}
+
+/// The unit of the opposite polarity.
+fn flip(unit: char) -> char {
+    if unit.is_ascii_uppercase() {
+        unit.to_ascii_lowercase()
+    } else {
+        unit.to_ascii_uppercase()
+    }
+}
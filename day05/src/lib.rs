@@ -0,0 +1,147 @@
+use aoc_common::error::single_line;
+use aoc_common::{NoConfig, ParseError, Solution};
+use std::error::Error;
+
+mod generate;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = String;
+    type Config = NoConfig;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let line = single_line(Self::DAY, input, "a polymer")?;
+
+        let polymer = line.text.trim();
+        match polymer
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_alphabetic())
+        {
+            Some((index, c)) => Err(line.error(
+                &polymer[index..index + c.len_utf8()],
+                "expected a unit letter",
+            )),
+            None => Ok(polymer.to_owned()),
+        }
+    }
+
+    fn part_one(polymer: &String, _: &NoConfig) -> Result<usize, Box<dyn Error>> {
+        Ok(reduce_polymer(polymer, None).len())
+    }
+
+    fn part_two(polymer: &String, _: &NoConfig) -> Result<usize, Box<dyn Error>> {
+        // Try removing all possible units and determine which polymer is the shortest
+        let improved_polymer = (b'a'..=b'z')
+            .map(|c| reduce_polymer(polymer, Some(c as char)))
+            .min_by_key(|p| p.len())
+            .ok_or("Could not find improved polymer.")?;
+
+        Ok(improved_polymer.len())
+    }
+}
+
+fn reduce_polymer(input: &str, ignore: Option<char>) -> Vec<char> {
+    // This approach uses a stack to keep track of when a reaction occurs.
+    // When iterating through each character, we check the top of the stack to see
+    // if a reaction occurs, if so the stack is popped, otherwise the unit is pushed
+    // on the stack.
+    //
+    // For part two, we need to figure out the shortest polymer that can be created
+    // by removing a single unit, so this function optionally allows an optionally
+    // specified unit to be ignored.
+    input.chars().fold(Vec::new(), |mut current_polymer, curr| {
+        if ignore
+            .map(|ignore| ignore.eq_ignore_ascii_case(&curr))
+            .unwrap_or(false)
+        {
+            return current_polymer;
+        }
+
+        if current_polymer
+            .last()
+            .map(|prev| reaction_occurs(*prev, curr))
+            .unwrap_or(false)
+        {
+            current_polymer.pop();
+        } else {
+            current_polymer.push(curr);
+        }
+
+        current_polymer
+    })
+}
+
+fn reaction_occurs(a: char, b: char) -> bool {
+    a.eq_ignore_ascii_case(&b) && a != b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reaction_occurs, reduce_polymer, Day05};
+    use aoc_common::{NoConfig, Solution};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "dabAcCaCBAcCcaDA";
+
+    fn reduced(ignore: Option<char>) -> String {
+        reduce_polymer(EXAMPLE, ignore).into_iter().collect()
+    }
+
+    #[test]
+    fn reactions() {
+        assert!(reaction_occurs('a', 'A'));
+        assert!(reaction_occurs('B', 'b'));
+        assert!(!reaction_occurs('a', 'a'));
+        assert!(!reaction_occurs('a', 'B'));
+    }
+
+    #[test]
+    fn fully_reacted() {
+        assert_eq!(reduced(None), "dabCBAcaDA");
+        assert_eq!(
+            Day05::part_one(&EXAMPLE.to_string(), &NoConfig {}).unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn removing_units() {
+        assert_eq!(reduced(Some('a')), "dbCBcD");
+        assert_eq!(reduced(Some('b')), "daCAcaDA");
+        assert_eq!(reduced(Some('c')), "daDA");
+        assert_eq!(reduced(Some('d')), "abCBAc");
+        assert_eq!(
+            Day05::part_two(&EXAMPLE.to_string(), &NoConfig {}).unwrap(),
+            4
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_polymer(polymer in "[a-zA-Z]{1,200}") {
+            prop_assert_eq!(Day05::parse(&format!("{}\n", polymer)).unwrap(), polymer);
+        }
+
+        #[test]
+        fn rejects_other_characters(
+            before in "[a-zA-Z]{0,20}",
+            bad in "[0-9.#-]",
+            after in "[a-zA-Z]{1,20}",
+        ) {
+            let err = Day05::parse(&format!("{}{}{}", before, bad, after)).unwrap_err();
+            prop_assert_eq!((err.column, err.text), (before.len() + 1, bad));
+        }
+
+        #[test]
+        fn rejects_multiple_lines(first in "[a-z]{1,10}", second in "[a-z]{1,10}") {
+            let err = Day05::parse(&format!("{}\n{}", first, second)).unwrap_err();
+            prop_assert_eq!(err.line, 2);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::Day06;
+use aoc_common::{Generator, Rng};
+
+impl Generator for Day06 {
+    /// Makes up `size` coordinates, spread over an area that grows with the number of coordinates
+    /// up to the largest area that can be scanned.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let side = (50.0 * (size as f64).sqrt()).clamp(10.0, 1000.0) as i64;
+        (0..size.max(1))
+            .map(|_| format!("{}, {}\n", rng.range(0..=side - 1), rng.range(0..=side - 1)))
+            .collect()
+    }
+}
@@ -0,0 +1,252 @@
+use aoc_common::error::lines;
+use aoc_common::{Grid, ParseError, Point, Rect, Solution, Vector};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+mod generate;
+mod render;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Locations are safe when their total distance to every coordinate is less than this.
+    pub safe_distance: i64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            safe_distance: 10000,
+        }
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Vec<Point>;
+    type Config = Config;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+        lines(Self::DAY, input)
+            .map(|line| {
+                let (x, y) = line.split_once(line.text, ",")?;
+                // Limiting the coordinates to 32 bits keeps distances between them from overflowing.
+                let x = line.parse::<i32>(x.trim(), "an x coordinate")?;
+                let y = line.parse::<i32>(y.trim(), "a y coordinate")?;
+                Ok(Point::new(i64::from(x), i64::from(y)))
+            })
+            .collect()
+    }
+
+    fn part_one(coordinates: &Vec<Point>, _: &Config) -> Result<u32, Box<dyn Error>> {
+        biggest_area(coordinates, find_bounds(coordinates)?)
+            .ok_or_else(|| "No coordinate has a finite area closest to it.".into())
+    }
+
+    fn part_two(coordinates: &Vec<Point>, config: &Config) -> Result<u32, Box<dyn Error>> {
+        let bounds = safe_bounds(coordinates, config.safe_distance)?;
+        Ok(safe_area(coordinates, bounds, config.safe_distance))
+    }
+}
+
+/// The most locations the coordinates can be spread over, since every location is scanned.
+const MAX_AREA: i64 = 1_000_000;
+
+fn find_bounds(coordinates: &[Point]) -> Result<Rect, Box<dyn Error>> {
+    match Rect::bounding(coordinates.iter().cloned()) {
+        Some(bounds) if too_big(bounds) => Err(format!(
+            "The coordinates are spread over {}x{} locations, more than the {} that can be scanned.",
+            bounds.width(),
+            bounds.height(),
+            MAX_AREA
+        )
+        .into()),
+        Some(bounds) => Ok(bounds),
+        None => Err("Could not find bounds!".into()),
+    }
+}
+
+/// Whether `bounds` covers more locations than can be scanned, without overflowing on the way.
+fn too_big(bounds: Rect) -> bool {
+    bounds
+        .width()
+        .checked_mul(bounds.height())
+        .is_none_or(|area| area > MAX_AREA)
+}
+
+/// The coordinates' bounds, widened far enough to take in every location that could be safe.
+///
+/// A location `n` steps outside the bounds is at least `n` steps from every coordinate, so it can
+/// only be safe when `n` times the number of coordinates is under `safe_distance`.
+fn safe_bounds(coordinates: &[Point], safe_distance: i64) -> Result<Rect, Box<dyn Error>> {
+    let bounds = find_bounds(coordinates)?;
+    // Anything wider than `MAX_AREA` is too big to scan anyway, so the margin can stop there.
+    let margin = (safe_distance.saturating_sub(1) / coordinates.len() as i64).clamp(0, MAX_AREA);
+    let margin = Vector::new(margin, margin);
+    let bounds = Rect::new(bounds.min - margin, bounds.max + margin);
+    if too_big(bounds) {
+        return Err(format!(
+            "Locations within a total distance of {} could be spread over more than the {} that \
+             can be scanned.",
+            safe_distance, MAX_AREA
+        )
+        .into());
+    }
+    Ok(bounds)
+}
+
+/// Builds a grid covering `bounds`, filling each cell with `f` called on the coordinate the cell
+/// represents.
+fn scan_bounds<T, F>(bounds: Rect, mut f: F) -> Grid<T>
+where
+    F: FnMut(Point) -> T,
+{
+    let width = bounds.width() as usize;
+    let height = bounds.height() as usize;
+    Grid::from_fn(width, height, |x, y| {
+        f(bounds.min + Vector::new(x as i64, y as i64))
+    })
+}
+
+/// The index of the coordinate closest to `location`, or `None` if several are equally close.
+fn closest(coordinates: &[Point], location: Point) -> Option<usize> {
+    let distances = coordinates
+        .iter()
+        .map(|coord| coord.manhattan_distance(location))
+        .collect::<Vec<_>>();
+    let min_dist = distances.iter().min()?;
+    let closest = distances
+        .iter()
+        .enumerate()
+        .filter(|(_, distance)| *distance == min_dist)
+        .collect::<Vec<_>>();
+    if closest.len() == 1 {
+        Some(closest[0].0)
+    } else {
+        None
+    }
+}
+
+/// The size of the largest finite area closest to a single coordinate, or `None` if there isn't
+/// one.
+///
+/// An area that reaches the edge of the bounds is infinite, since every location past that edge is
+/// closest to the same coordinate as the location on the edge.
+fn biggest_area(coordinates: &[Point], bounds: Rect) -> Option<u32> {
+    let closest = scan_bounds(bounds, |location| closest(coordinates, location));
+    let (width, height) = (closest.width(), closest.height());
+
+    let mut areas: HashMap<usize, u32> = HashMap::new();
+    let mut infinite = HashSet::new();
+    for ((x, y), index) in closest.iter() {
+        if let Some(index) = index {
+            *areas.entry(*index).or_default() += 1;
+            if x == 0 || y == 0 || x + 1 == width || y + 1 == height {
+                infinite.insert(*index);
+            }
+        }
+    }
+
+    areas
+        .iter()
+        .filter(|(index, _)| !infinite.contains(*index))
+        .map(|(_, area)| *area)
+        .max()
+}
+
+// Part 2: Find the number of locations where the sum of the distance from that location to
+// all coordinates is less than `max_total_distance`
+fn safe_area(coordinates: &[Point], bounds: Rect, max_total_distance: i64) -> u32 {
+    let total_distances = scan_bounds(bounds, |location| {
+        coordinates
+            .iter()
+            .map(|coord| coord.manhattan_distance(location))
+            .sum::<i64>()
+    });
+
+    total_distances
+        .cells()
+        .filter(|total_distance| **total_distance < max_total_distance)
+        .count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{biggest_area, find_bounds, safe_area, safe_bounds, Config, Day06};
+    use aoc_common::{Point, Solution};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9";
+
+    #[test]
+    fn largest_finite_area() {
+        let coordinates = Day06::parse(EXAMPLE).unwrap();
+        let bounds = find_bounds(&coordinates).unwrap();
+        assert_eq!(biggest_area(&coordinates, bounds), Some(17));
+
+        // The corners' areas are bigger, but they go on forever.
+        let coordinates =
+            Day06::parse("0, 0\n20, 0\n0, 20\n20, 20\n10, 10\n10, 7\n10, 13\n7, 10\n13, 10")
+                .unwrap();
+        let bounds = find_bounds(&coordinates).unwrap();
+        assert_eq!(biggest_area(&coordinates, bounds), Some(9));
+
+        let coordinates = Day06::parse("0, 0\n4, 4").unwrap();
+        let bounds = find_bounds(&coordinates).unwrap();
+        assert_eq!(biggest_area(&coordinates, bounds), None);
+    }
+
+    #[test]
+    fn unscannable_coordinates() {
+        let coordinates = Day06::parse("0, 0\n0, 0").unwrap();
+        assert!(Day06::part_one(&coordinates, &Default::default()).is_err());
+        let coordinates = Day06::parse("0, 0\n2000000000, 2000000000").unwrap();
+        assert!(find_bounds(&coordinates).is_err());
+        assert!(Day06::parse("3000000000, 0").is_err());
+    }
+
+    #[test]
+    fn safe_region() {
+        let coordinates = Day06::parse(EXAMPLE).unwrap();
+        let bounds = find_bounds(&coordinates).unwrap();
+        assert_eq!(safe_area(&coordinates, bounds, 32), 16);
+
+        // Most of the region is outside the coordinates' bounds.
+        let coordinates = Day06::parse("0, 0\n1, 1").unwrap();
+        let bounds = safe_bounds(&coordinates, 10).unwrap();
+        assert_eq!(safe_area(&coordinates, bounds, 10), 40);
+        let part_two = Day06::part_two(&coordinates, &Config { safe_distance: 10 });
+        assert_eq!(part_two.unwrap(), 40);
+
+        let coordinates = Day06::parse(EXAMPLE).unwrap();
+        assert!(safe_bounds(&coordinates, 100_000_000).is_err());
+        assert!(safe_bounds(&coordinates, i64::MIN).is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_coordinates(
+            coordinates in prop::collection::vec((0i64..1000, 0i64..1000), 0..50),
+        ) {
+            let input = coordinates
+                .iter()
+                .map(|(x, y)| format!("{}, {}\n", x, y))
+                .collect::<String>();
+            let points = coordinates.iter().map(|&(x, y)| Point::new(x, y)).collect::<Vec<_>>();
+            prop_assert_eq!(Day06::parse(&input).unwrap(), points);
+        }
+
+        #[test]
+        fn rejects_malformed_coordinates(line in "[0-9]{1,3}(;| |, ?[a-z]+)[0-9]{0,3}") {
+            let err = Day06::parse(&format!("1, 2\n{}", line)).unwrap_err();
+            prop_assert_eq!(err.line, 2);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{closest, find_bounds, scan_bounds, Config, Day06};
+use aoc_common::{Image, Point, Render, Rgb};
+use std::error::Error;
+
+const TIED: Rgb = Rgb::new(40, 40, 40);
+
+impl Render for Day06 {
+    /// Draws the area around the coordinates, coloured by which coordinate each location is
+    /// closest to. Locations outside of the safe region are darker, and the coordinates
+    /// themselves are white.
+    fn render(coordinates: &Vec<Point>, config: &Config) -> Result<Image, Box<dyn Error>> {
+        let bounds = find_bounds(coordinates)?;
+        let colours = scan_bounds(bounds, |location| {
+            let colour = match closest(coordinates, location) {
+                Some(index) => Rgb::distinct(index),
+                None => TIED,
+            };
+            let total_distance = coordinates
+                .iter()
+                .map(|coord| coord.manhattan_distance(location))
+                .sum::<i64>();
+            if total_distance < config.safe_distance {
+                colour
+            } else {
+                colour.mix(Rgb::BLACK, 0.6)
+            }
+        });
+
+        let mut image = Image::from_grid(&colours, |colour| *colour)?;
+        for coordinate in coordinates {
+            let offset = *coordinate - bounds.min;
+            image.set(Point::new(offset.x, offset.y), Rgb::WHITE);
+        }
+        Ok(image)
+    }
+}
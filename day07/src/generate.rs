@@ -0,0 +1,31 @@
+use crate::Day07;
+use aoc_common::{Generator, Rng};
+
+impl Generator for Day07 {
+    /// Makes up `size` instructions between the steps `A` to `Z`, capped at the 325 pairs of
+    /// steps there are. The steps are put in a random order first and each instruction only goes
+    /// forwards in it, so the instructions never go round in a circle.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut steps = (b'A'..=b'Z').map(char::from).collect::<Vec<_>>();
+        rng.shuffle(&mut steps);
+
+        let mut instructions = Vec::new();
+        for (index, before) in steps.iter().enumerate() {
+            for after in &steps[index + 1..] {
+                instructions.push((before, after));
+            }
+        }
+        rng.shuffle(&mut instructions);
+
+        instructions
+            .iter()
+            .take(size.max(1))
+            .map(|(before, after)| {
+                format!(
+                    "Step {} must be finished before step {} can begin.\n",
+                    before, after
+                )
+            })
+            .collect()
+    }
+}
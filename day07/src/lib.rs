@@ -0,0 +1,300 @@
+use aoc_common::error::lines;
+use aoc_common::{ParseError, Solution};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use tracing::{debug, trace};
+
+mod generate;
+
+type Step = char;
+
+pub type DependencyTree = HashMap<Step, Vec<Step>>;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// How many steps can be worked on at once.
+    pub workers: usize,
+    /// How long every step takes, on top of a second for each letter of the alphabet up to the
+    /// step.
+    pub base_duration: u32,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            workers: 4,
+            base_duration: 60,
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = DependencyTree;
+    type Config = Config;
+    type PartOne = String;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<DependencyTree, ParseError> {
+        let re = Regex::new(
+            r"^\s*Step (?P<prereq>[A-Z]) must be finished before step (?P<step>[A-Z]) can begin\.\s*$",
+        )
+        .expect("step pattern is valid");
+
+        let steps = lines(Self::DAY, input)
+            .map(|line| {
+                let caps = re.captures(line.text).ok_or_else(|| {
+                    line.invalid("expected `Step X must be finished before step Y can begin.`")
+                })?;
+                let prereq = caps["prereq"].as_bytes()[0] as Step;
+                let step = caps["step"].as_bytes()[0] as Step;
+
+                Ok((prereq, step))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let dependencies: DependencyTree =
+            steps
+                .iter()
+                .fold(HashMap::new(), |mut tree, (prereq, step)| {
+                    tree.entry(*step).or_default().push(*prereq);
+                    tree.entry(*prereq).or_default();
+                    tree
+                });
+
+        Ok(dependencies)
+    }
+
+    fn part_one(dependencies: &DependencyTree, _: &Config) -> Result<String, Box<dyn Error>> {
+        Ok(part1(dependencies)?.iter().collect())
+    }
+
+    fn part_two(dependencies: &DependencyTree, config: &Config) -> Result<u32, Box<dyn Error>> {
+        if config.workers == 0 {
+            return Err("At least one worker is needed to build the sleigh.".into());
+        }
+        Ok(part2(dependencies, config.workers, config.base_duration)?)
+    }
+}
+
+fn part1(dependencies: &DependencyTree) -> Result<Vec<Step>, String> {
+    let mut completed_steps: HashSet<Step> = HashSet::new();
+    let mut order = Vec::new();
+    loop {
+        let next_steps = compute_next_steps(dependencies, &completed_steps, None);
+        match next_steps.first() {
+            None => break,
+            Some(&step) => {
+                trace!(%step, available = next_steps.len(), "taking the next step");
+                order.push(step);
+                completed_steps.insert(step);
+            }
+        }
+    }
+    all_completed(dependencies, &completed_steps)?;
+    Ok(order)
+}
+
+fn part2(dependencies: &DependencyTree, workers: usize, base_duration: u32) -> Result<u32, String> {
+    let mut completed_steps: HashSet<Step> = HashSet::new();
+    let mut worker_pool: Vec<Worker> = Vec::new();
+    let mut time = 0u32;
+    loop {
+        for worker in &mut worker_pool {
+            worker.time_remaining -= 1;
+            if worker.time_remaining == 0 {
+                debug!(time, step = %worker.current_step, "finished step");
+                completed_steps.insert(worker.current_step);
+            }
+        }
+        worker_pool.retain(|worker| worker.time_remaining > 0);
+
+        let next_steps = compute_next_steps(dependencies, &completed_steps, Some(&worker_pool));
+        let open_slots = workers - worker_pool.len();
+        let steps = next_steps.iter().take(open_slots);
+
+        for step in steps {
+            let duration = duration(*step, base_duration).ok_or(TOO_LONG)?;
+            debug!(time, step = %step, duration, "started step");
+            worker_pool.push(Worker {
+                current_step: *step,
+                time_remaining: duration,
+            });
+        }
+
+        if worker_pool.is_empty() {
+            break;
+        }
+
+        trace!(
+            time,
+            busy = worker_pool.len(),
+            waiting = next_steps.len().saturating_sub(open_slots),
+            "scheduled"
+        );
+        time = time.checked_add(1).ok_or(TOO_LONG)?;
+    }
+
+    all_completed(dependencies, &completed_steps)?;
+    Ok(time)
+}
+
+const TOO_LONG: &str = "The steps take too long to time.";
+
+/// Checks that no steps were left waiting on prerequisites that could never be finished.
+fn all_completed(
+    dependencies: &DependencyTree,
+    completed_steps: &HashSet<Step>,
+) -> Result<(), String> {
+    let mut stuck = dependencies
+        .keys()
+        .filter(|step| !completed_steps.contains(step))
+        .collect::<Vec<_>>();
+    if stuck.is_empty() {
+        return Ok(());
+    }
+    stuck.sort();
+    Err(format!(
+        "These steps can never begin, since they are stuck behind a cycle of prerequisites: {}",
+        stuck.into_iter().collect::<String>()
+    ))
+}
+
+fn compute_next_steps(
+    dependencies: &DependencyTree,
+    completes_steps: &HashSet<Step>,
+    workers: Option<&[Worker]>,
+) -> Vec<Step> {
+    let mut next_steps = dependencies
+        .iter()
+        .filter(|(step, _)| !completes_steps.contains(step))
+        .filter(|(step, _)| {
+            workers
+                .map(|workers| !workers.iter().any(|worker| worker.current_step == **step))
+                .unwrap_or(true)
+        })
+        .filter(|(_, prereqs)| {
+            prereqs
+                .iter()
+                .all(|prereq| completes_steps.contains(prereq))
+        })
+        .map(|(step, _)| *step)
+        .collect::<Vec<_>>();
+    next_steps.sort();
+    next_steps
+}
+
+struct Worker {
+    current_step: Step,
+    time_remaining: u32,
+}
+
+fn duration(step: Step, base_duration: u32) -> Option<u32> {
+    base_duration.checked_add(u32::from(step as u8 - 64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{duration, part1, part2, Day07, DependencyTree};
+    use aoc_common::Solution;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.";
+
+    #[test]
+    fn step_order() {
+        let dependencies = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            part1(&dependencies).unwrap().iter().collect::<String>(),
+            "CABDFE"
+        );
+    }
+
+    #[test]
+    fn dependency_cycles() {
+        let dependencies =
+            Day07::parse("Step A must be finished before step A can begin.").unwrap();
+        assert!(part1(&dependencies).is_err());
+        assert!(part2(&dependencies, 2, 0).is_err());
+
+        let dependencies = Day07::parse(&format!(
+            "{}\nStep E must be finished before step G can begin.\n\
+             Step G must be finished before step D can begin.",
+            EXAMPLE
+        ))
+        .unwrap();
+        assert_eq!(
+            part1(&dependencies),
+            Err(
+                "These steps can never begin, since they are stuck behind a cycle of prerequisites: DEG"
+                    .to_string()
+            )
+        );
+        assert!(part2(&dependencies, 2, 0).is_err());
+    }
+
+    #[test]
+    fn step_durations() {
+        assert_eq!(duration('A', 60), Some(61));
+        assert_eq!(duration('Z', 60), Some(86));
+        assert_eq!(duration('C', 0), Some(3));
+        assert_eq!(duration('A', u32::MAX), None);
+    }
+
+    #[test]
+    fn time_with_workers() {
+        let dependencies = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&dependencies, 2, 0), Ok(15));
+        assert!(part2(&dependencies, 2, u32::MAX).is_err());
+    }
+
+    fn steps() -> impl Strategy<Value = (char, char)> {
+        (b'A'..=b'Z', b'A'..=b'Z')
+            .prop_filter("a step can't depend on itself", |(a, b)| a != b)
+            .prop_map(|(a, b)| (a as char, b as char))
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_dependencies(steps in prop::collection::vec(steps(), 1..50)) {
+            let input = steps
+                .iter()
+                .map(|(prereq, step)| {
+                    format!("Step {} must be finished before step {} can begin.\n", prereq, step)
+                })
+                .collect::<String>();
+
+            let mut expected = DependencyTree::new();
+            for &(prereq, step) in &steps {
+                expected.entry(step).or_default().push(prereq);
+                expected.entry(prereq).or_default();
+            }
+            prop_assert_eq!(Day07::parse(&input).unwrap(), expected);
+        }
+
+        #[test]
+        fn rejects_malformed_steps(prereq in "[a-z0-9]|[A-Z]{2}", step in "[A-Z]") {
+            let input = format!(
+                "Step A must be finished before step B can begin.\n\
+                 Step {} must be finished before step {} can begin.",
+                prereq, step
+            );
+            let err = Day07::parse(&input).unwrap_err();
+            prop_assert_eq!((err.line, err.column), (2, 1));
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::Day08;
+use aoc_common::{Generator, Rng};
+
+/// How deep the generated trees are allowed to get, well within what the parser accepts.
+const MAX_DEPTH: usize = 50;
+
+impl Generator for Day08 {
+    /// Makes up a license for a tree of `size` nodes, with each node added under a random
+    /// earlier node.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let count = size.max(1);
+        let mut children = vec![Vec::new(); count];
+        let mut depths = vec![0; count];
+        let mut parents = vec![0; count];
+        for node in 1..count {
+            let mut parent = rng.index(node);
+            while depths[parent] >= MAX_DEPTH {
+                parent = parents[parent];
+            }
+            parents[node] = parent;
+            depths[node] = depths[parent] + 1;
+            children[parent].push(node);
+        }
+
+        let mut license = Vec::new();
+        write_node(0, &children, rng, &mut license);
+        license.join(" ") + "\n"
+    }
+}
+
+fn write_node(node: usize, children: &[Vec<usize>], rng: &mut Rng, license: &mut Vec<String>) {
+    let child_count = children[node].len();
+    let metadata_count = rng.range(1..=5);
+    license.push(child_count.to_string());
+    license.push(metadata_count.to_string());
+
+    for child in &children[node] {
+        write_node(*child, children, rng, license);
+    }
+
+    // Entries of nodes with children mostly refer to one of them, and sometimes to nothing.
+    let max_entry = if child_count == 0 {
+        9
+    } else {
+        child_count as i64 + 1
+    };
+    for _ in 0..metadata_count {
+        license.push(rng.range(1..=max_entry).to_string());
+    }
+}
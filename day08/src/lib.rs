@@ -0,0 +1,243 @@
+use aoc_common::error::{lines, Line};
+use aoc_common::{NoConfig, ParseError, Solution};
+use std::error::Error;
+
+mod generate;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Node;
+    type Config = NoConfig;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Node, ParseError> {
+        let mut tokens = lines(Self::DAY, input)
+            .flat_map(|line| line.text.split_whitespace().map(move |value| (line, value)));
+
+        let head = get_node(&mut tokens, input, 0)?;
+
+        match tokens.next() {
+            Some((line, value)) => Err(line.error(value, "expected the end of the license")),
+            None => Ok(head),
+        }
+    }
+
+    fn part_one(head: &Node, _: &NoConfig) -> Result<u64, Box<dyn Error>> {
+        Ok(head.tree_metadata_sum())
+    }
+
+    fn part_two(head: &Node, _: &NoConfig) -> Result<u64, Box<dyn Error>> {
+        head.value()
+            .ok_or_else(|| "The value of the root node is too large.".into())
+    }
+}
+
+/// How deeply nodes can be nested before the license is rejected, so that walking the tree can't
+/// overflow the stack.
+const MAX_DEPTH: usize = 100;
+
+fn get_node<'a, I>(tokens: &mut I, license: &str, depth: usize) -> Result<Node, ParseError>
+where
+    I: Iterator<Item = (Line<'a>, &'a str)>,
+{
+    let (line, value) = next_token(tokens, license, "a child count")?;
+    if depth >= MAX_DEPTH {
+        return Err(line.error(
+            value,
+            format!("expected nodes to be nested at most {} deep", MAX_DEPTH),
+        ));
+    }
+    let child_count = line.parse(value, "a child count")?;
+    let metadata_count = next_value(tokens, license, "a metadata count")?;
+    let mut node = Node::new(child_count);
+
+    for _ in 0..child_count {
+        node.children.push(get_node(tokens, license, depth + 1)?);
+    }
+
+    for _ in 0..metadata_count {
+        node.metadata
+            .push(next_value(tokens, license, "a metadata entry")?);
+    }
+
+    Ok(node)
+}
+
+fn next_value<'a, I>(tokens: &mut I, license: &str, expected: &str) -> Result<u32, ParseError>
+where
+    I: Iterator<Item = (Line<'a>, &'a str)>,
+{
+    let (line, value) = next_token(tokens, license, expected)?;
+    line.parse(value, expected)
+}
+
+fn next_token<'a, I>(
+    tokens: &mut I,
+    license: &str,
+    expected: &str,
+) -> Result<(Line<'a>, &'a str), ParseError>
+where
+    I: Iterator<Item = (Line<'a>, &'a str)>,
+{
+    tokens
+        .next()
+        .ok_or_else(|| ParseError::at_end(Day08::DAY, license, format!("expected {}", expected)))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    child_count: u32,
+    children: Vec<Node>,
+    metadata: Vec<u32>,
+}
+
+impl Node {
+    // The counts come from the license, so space is only reserved once the entries turn up.
+    fn new(child_count: u32) -> Node {
+        Node {
+            child_count,
+            children: Vec::new(),
+            metadata: Vec::new(),
+        }
+    }
+
+    fn metadata_sum(&self) -> u64 {
+        self.metadata.iter().map(|entry| u64::from(*entry)).sum()
+    }
+
+    fn tree_metadata_sum(&self) -> u64 {
+        self.metadata_sum()
+            + self
+                .children
+                .iter()
+                .map(|child| child.tree_metadata_sum())
+                .sum::<u64>()
+    }
+
+    /// The node's value, or `None` if it doesn't fit in a `u64`.
+    ///
+    /// Each child's value is only worked out once, however many metadata entries refer to it.
+    fn value(&self) -> Option<u64> {
+        if self.child_count == 0 {
+            return Some(self.metadata_sum());
+        }
+
+        let child_values = self
+            .children
+            .iter()
+            .map(|child| child.value())
+            .collect::<Option<Vec<_>>>()?;
+        self.metadata
+            .iter()
+            .filter(|entry| **entry > 0)
+            .filter_map(|entry| child_values.get(*entry as usize - 1))
+            .try_fold(0u64, |total, value| total.checked_add(*value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day08, Node};
+    use aoc_common::{NoConfig, ParseError, Solution};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+
+    #[test]
+    fn metadata_sum() {
+        let head = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(head.children.len(), 2);
+        assert_eq!(head.children[0].tree_metadata_sum(), 33);
+        assert_eq!(Day08::part_one(&head, &NoConfig {}).unwrap(), 138);
+    }
+
+    #[test]
+    fn node_values() {
+        let head = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(head.children[0].value(), Some(33));
+        assert_eq!(head.children[1].value(), Some(0));
+        assert_eq!(Day08::part_two(&head, &NoConfig {}).unwrap(), 66);
+    }
+
+    #[test]
+    fn truncated_license() {
+        assert_eq!(
+            Day08::parse("1 1\n0 2 7").unwrap_err(),
+            ParseError::new(8, 2, 6, "", "expected a metadata entry")
+        );
+    }
+
+    #[test]
+    fn trailing_values() {
+        assert_eq!(
+            Day08::parse("0 1 5 3").unwrap_err(),
+            ParseError::new(8, 1, 7, "3", "expected the end of the license")
+        );
+    }
+
+    #[test]
+    fn deeply_nested_license() {
+        let license = "1 0 ".repeat(super::MAX_DEPTH) + "0 0";
+        assert_eq!(
+            Day08::parse(&license).unwrap_err().message,
+            "expected nodes to be nested at most 100 deep"
+        );
+    }
+
+    #[test]
+    fn huge_values() {
+        // Every node refers to its only child three times, tripling the value at each level.
+        let license = "1 3 ".repeat(50) + "0 1 1" + &" 1 1 1".repeat(50);
+        let head = Day08::parse(&license).unwrap();
+        assert!(Day08::part_two(&head, &NoConfig {}).is_err());
+    }
+
+    fn node() -> impl Strategy<Value = Node> {
+        let metadata = prop::collection::vec(0u32..100, 0..5);
+        let leaf = metadata.clone().prop_map(|metadata| Node {
+            child_count: 0,
+            children: Vec::new(),
+            metadata,
+        });
+        leaf.prop_recursive(4, 64, 4, move |child| {
+            (prop::collection::vec(child, 0..4), metadata.clone()).prop_map(
+                |(children, metadata)| Node {
+                    child_count: children.len() as u32,
+                    children,
+                    metadata,
+                },
+            )
+        })
+    }
+
+    fn license(node: &Node) -> Vec<String> {
+        let mut values = vec![
+            node.child_count.to_string(),
+            node.metadata.len().to_string(),
+        ];
+        for child in &node.children {
+            values.extend(license(child));
+        }
+        values.extend(node.metadata.iter().map(|entry| entry.to_string()));
+        values
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_tree(node in node()) {
+            prop_assert_eq!(Day08::parse(&license(&node).join(" ")).unwrap(), node);
+        }
+
+        #[test]
+        fn rejects_truncated_licenses(node in node(), cut in any::<prop::sample::Index>()) {
+            let values = license(&node);
+            let truncated = &values[..cut.index(values.len())];
+            prop_assert!(Day08::parse(&truncated.join(" ")).is_err());
+        }
+    }
+}
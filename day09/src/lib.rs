@@ -0,0 +1,297 @@
+use aoc_common::error::single_line;
+use aoc_common::{ParseError, Solution};
+use regex::Regex;
+use serde::Deserialize;
+use std::error::Error;
+
+/// The rules of a marble game.
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    players: u32,
+    last_marble: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// How many times larger the last marble is in part two.
+    pub multiplier: u32,
+    /// The largest last marble to play a game up to, since every marble is kept in memory. It can
+    /// be at most a billion.
+    pub max_marbles: u32,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            multiplier: 100,
+            max_marbles: 100_000_000,
+        }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Game;
+    type Config = Config;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Game, ParseError> {
+        let re = Regex::new(
+            r"^\s*(?P<players>\d+) players; last marble is worth (?P<last_marble>\d+) points\s*$",
+        )
+        .expect("game pattern is valid");
+
+        let line = single_line(Self::DAY, input, "the game rules")?;
+
+        let captures = re
+            .captures(line.text)
+            .ok_or_else(|| line.invalid("expected `N players; last marble is worth M points`"))?;
+
+        let players = captures.name("players").unwrap().as_str();
+        let last_marble = captures.name("last_marble").unwrap().as_str();
+        let players = match line.parse::<u32>(players, "a player count")? {
+            0 => return Err(line.error(players, "expected at least one player")),
+            count => count,
+        };
+        let last_marble = line.parse(last_marble, "a marble value")?;
+
+        Ok(Game {
+            players,
+            last_marble,
+        })
+    }
+
+    fn part_one(game: &Game, config: &Config) -> Result<u64, Box<dyn Error>> {
+        play_limited_game(game.players, game.last_marble, config)
+    }
+
+    fn part_two(game: &Game, config: &Config) -> Result<u64, Box<dyn Error>> {
+        let last_marble = game
+            .last_marble
+            .checked_mul(config.multiplier)
+            .ok_or("The last marble is too large.")?;
+        play_limited_game(game.players, last_marble, config)
+    }
+}
+
+/// The most marbles a game can ever be played with, which takes around 24GB of memory.
+const MARBLE_LIMIT: u32 = 1_000_000_000;
+
+fn play_limited_game(
+    player_count: u32,
+    last_marble: u32,
+    config: &Config,
+) -> Result<u64, Box<dyn Error>> {
+    if config.max_marbles > MARBLE_LIMIT {
+        return Err(format!(
+            "A game can be played with at most {} marbles, not {}.",
+            MARBLE_LIMIT, config.max_marbles
+        )
+        .into());
+    }
+    if last_marble > config.max_marbles {
+        return Err(format!(
+            "The last marble is worth {}, more than the limit of {}.",
+            last_marble, config.max_marbles
+        )
+        .into());
+    }
+    play_game(player_count, last_marble)
+}
+
+fn play_game(player_count: u32, last_marble: u32) -> Result<u64, Box<dyn Error>> {
+    // Players after the last marble's player never get a turn, so they don't need to be tracked.
+    let mut players = (1..=player_count.min(last_marble.max(1)))
+        .map(Player::new)
+        .collect::<Vec<_>>();
+    let mut circle = Circle::new(last_marble as usize)?;
+    for (player_id, value) in (0..player_count).cycle().zip(1..=last_marble) {
+        if let Some(score) = circle.play_turn(value) {
+            players[player_id as usize].score += score;
+        }
+    }
+
+    Ok(players.iter().map(|player| player.score).max().unwrap())
+}
+
+struct Player {
+    _id: u32,
+    score: u64,
+}
+
+impl Player {
+    fn new(id: u32) -> Player {
+        Player { _id: id, score: 0 }
+    }
+}
+
+type MarbleId = usize;
+struct Marble {
+    value: u32,
+    clockwise: MarbleId,
+    counter_clockwise: MarbleId,
+}
+
+impl Marble {
+    fn new(value: u32, clockwise: MarbleId, counter_clockwise: MarbleId) -> Marble {
+        Marble {
+            value,
+            clockwise,
+            counter_clockwise,
+        }
+    }
+}
+
+struct Circle {
+    marbles: Vec<Marble>,
+    current_marble: MarbleId,
+}
+
+impl Circle {
+    fn new(max: usize) -> Result<Circle, String> {
+        let mut marbles = Vec::new();
+        marbles
+            .try_reserve_exact(max + 1)
+            .map_err(|_| format!("There isn't enough memory for {} marbles.", max))?;
+        marbles.push(Marble::new(0, 0, 0));
+        Ok(Circle {
+            marbles,
+            current_marble: 0,
+        })
+    }
+
+    fn play_turn(&mut self, value: u32) -> Option<u64> {
+        if !value.is_multiple_of(23) {
+            let marble_before = self.clockwise(1);
+            self.current_marble = self.insert_after(value, marble_before);
+            None
+        } else {
+            let marble_to_remove = self.counter_clockwise(7);
+            self.current_marble = self.marbles[marble_to_remove].clockwise;
+            self.remove(marble_to_remove);
+            Some(u64::from(value) + u64::from(self.marbles[marble_to_remove].value))
+        }
+    }
+
+    fn clockwise(&self, distance: usize) -> MarbleId {
+        let mut result = self.current_marble;
+        for _ in 0..distance {
+            result = self.marbles[result].clockwise;
+        }
+        result
+    }
+
+    fn counter_clockwise(&self, distance: usize) -> MarbleId {
+        let mut result = self.current_marble;
+        for _ in 0..distance {
+            result = self.marbles[result].counter_clockwise;
+        }
+        result
+    }
+
+    fn insert_after(&mut self, value: u32, marble: MarbleId) -> MarbleId {
+        let before = marble;
+        let after = self.marbles[marble].clockwise;
+        let marble = Marble::new(value, after, before);
+        self.marbles.push(marble);
+        let id = self.marbles.len() - 1;
+        self.marbles[before].clockwise = id;
+        self.marbles[after].counter_clockwise = id;
+        id
+    }
+
+    fn remove(&mut self, marble: MarbleId) {
+        let before = self.marbles[marble].counter_clockwise;
+        let after = self.marbles[marble].clockwise;
+
+        self.marbles[before].clockwise = after;
+        self.marbles[after].counter_clockwise = before;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{play_game, Circle, Config, Day09, Game};
+    use aoc_common::Solution;
+    use proptest::prelude::*;
+
+    #[test]
+    fn high_scores() {
+        assert_eq!(play_game(9, 25).unwrap(), 32);
+        assert_eq!(play_game(10, 1618).unwrap(), 8317);
+        assert_eq!(play_game(13, 7999).unwrap(), 146373);
+        assert_eq!(play_game(17, 1104).unwrap(), 2764);
+        assert_eq!(play_game(21, 6111).unwrap(), 54718);
+        assert_eq!(play_game(30, 5807).unwrap(), 37305);
+    }
+
+    #[test]
+    fn parse_rules() {
+        let game = Day09::parse("10 players; last marble is worth 1618 points").unwrap();
+        assert_eq!((game.players, game.last_marble), (10, 1618));
+        assert_eq!(Day09::part_one(&game, &Config::default()).unwrap(), 8317);
+
+        let config = Config {
+            multiplier: 1,
+            ..Config::default()
+        };
+        assert_eq!(Day09::part_two(&game, &config).unwrap(), 8317);
+    }
+
+    #[test]
+    fn huge_games() {
+        let game = Day09::parse("4294967295 players; last marble is worth 100 points").unwrap();
+        assert_eq!(
+            Day09::part_one(&game, &Config::default()).unwrap(),
+            play_game(100, 100).unwrap()
+        );
+
+        let game = Day09::parse("10 players; last marble is worth 4294967295 points").unwrap();
+        assert!(Day09::part_one(&game, &Config::default()).is_err());
+        assert!(Day09::part_two(&game, &Config::default()).is_err());
+
+        let config = Config {
+            multiplier: u32::MAX,
+            max_marbles: u32::MAX,
+        };
+        assert!(Day09::part_one(&game, &config).is_err());
+        let game = Day09::parse("10 players; last marble is worth 1 points").unwrap();
+        assert!(Day09::part_two(&game, &config).is_err());
+    }
+
+    #[test]
+    fn scores_past_32_bits() {
+        let mut circle = Circle::new(23).unwrap();
+        for _ in 0..8 {
+            circle.current_marble = circle.insert_after(u32::MAX, circle.current_marble);
+        }
+        let value = u32::MAX / 23 * 23;
+        assert_eq!(
+            circle.play_turn(value),
+            Some(u64::from(value) + u64::from(u32::MAX))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_game(players in 1u32..1000, last_marble in 0u32..10_000_000) {
+            let input = format!(
+                "{} players; last marble is worth {} points\n",
+                players, last_marble
+            );
+            prop_assert_eq!(Day09::parse(&input).unwrap(), Game { players, last_marble });
+        }
+
+        #[test]
+        fn rejects_malformed_games(players in "0|-[0-9]{1,3}|[a-z]{1,5}") {
+            let input = format!("{} players; last marble is worth 100 points", players);
+            prop_assert!(Day09::parse(&input).is_err());
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{Config, Day10, Light};
+use aoc_common::{Animate, Grid, Point, Rect, Simulation};
+use std::error::Error;
+
+impl Animate for Day10 {
+    fn animate(
+        lights: &Vec<Light>,
+        config: &Config,
+    ) -> Result<Box<dyn Simulation>, Box<dyn Error>> {
+        Ok(Box::new(Sky {
+            lights: lights.clone(),
+            second: 0,
+            max_seconds: config.max_seconds,
+        }))
+    }
+}
+
+struct Sky {
+    lights: Vec<Light>,
+    second: u32,
+    max_seconds: u32,
+}
+
+impl Simulation for Sky {
+    fn step(&mut self) -> Result<bool, Box<dyn Error>> {
+        if self.second == self.max_seconds {
+            return Ok(false);
+        }
+        for light in &mut self.lights {
+            light.pos += light.vel;
+        }
+        self.second += 1;
+        Ok(true)
+    }
+
+    /// Draws the sky shrunk down to fit, so the lights can be seen gathering long before they are
+    /// close enough to draw one to a character.
+    fn frame(&self, width: usize, height: usize) -> String {
+        let bounds = match Rect::bounding(self.lights.iter().map(|light| light.pos)) {
+            Some(bounds) => bounds,
+            None => return "There are no lights.".to_string(),
+        };
+        let height = height.saturating_sub(1).max(1) as i64;
+        let width = width.max(1) as i64;
+
+        // Every character covers a `scale` by `scale` patch of sky.
+        let scale = ((bounds.width() + width - 1) / width)
+            .max((bounds.height() + height - 1) / height)
+            .max(1);
+        let size = |length: i64| ((length + scale - 1) / scale) as usize;
+        let mut sky = Grid::new(size(bounds.width()), size(bounds.height()), '.');
+        for light in &self.lights {
+            let offset = light.pos - bounds.min;
+            sky[Point::new(offset.x / scale, offset.y / scale)] = '#';
+        }
+
+        let caption = format!("Lights spread over {}x{}", bounds.width(), bounds.height());
+        if scale > 1 {
+            format!("{}, shrunk {} times\n{}", caption, scale, sky)
+        } else {
+            format!("{}\n{}", caption, sky)
+        }
+    }
+}
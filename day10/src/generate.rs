@@ -0,0 +1,31 @@
+use crate::Day10;
+use aoc_common::{Generator, Rng};
+
+/// How tall the message is.
+const HEIGHT: i64 = 10;
+
+impl Generator for Day10 {
+    /// Makes up `size` lights that all come together into a random message, as wide as the
+    /// number of lights allows, at some second between 10000 and 20000.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let count = size.max(1);
+        let width = (count as i64 / 4).clamp(8, 190);
+        let second = rng.range(10_000..=20_000);
+
+        (0..count)
+            .map(|_| {
+                let (x, y) = (rng.range(0..=width - 1), rng.range(0..=HEIGHT - 1));
+                // Every light moves up or down, so they are only ever close together at the end.
+                let dx = rng.range(-5..=5);
+                let dy = rng.range(1..=5) * if rng.chance(1, 2) { 1 } else { -1 };
+                format!(
+                    "position=<{:>7}, {:>7}> velocity=<{:>2}, {:>2}>\n",
+                    x - dx * second,
+                    y - dy * second,
+                    dx,
+                    dy
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,261 @@
+use aoc_common::error::lines;
+use aoc_common::{Grid, ParseError, Point, Rect, Solution, Vector};
+use regex::Regex;
+use serde::Deserialize;
+use std::error::Error;
+use tracing::{debug, trace};
+
+mod animate;
+mod generate;
+mod render;
+
+/// A point of light moving through the sky.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Light {
+    pos: Point,
+    vel: Vector,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// How many seconds to wait for the message before giving up.
+    pub max_seconds: u32,
+    /// The tallest the lights can be spread while spelling out the message.
+    pub message_height: i64,
+    /// The widest the lights can be spread while spelling out the message.
+    pub message_width: i64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            max_seconds: 50000,
+            message_height: 16,
+            message_width: 200,
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Vec<Light>;
+    type Config = Config;
+    type PartOne = String;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<Light>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(lights: &Vec<Light>, config: &Config) -> Result<String, Box<dyn Error>> {
+        let (_, sky) = find_message(lights.clone(), config)?;
+        Ok(sky)
+    }
+
+    fn part_two(lights: &Vec<Light>, config: &Config) -> Result<u32, Box<dyn Error>> {
+        let (second, _) = find_message(lights.clone(), config)?;
+        Ok(second)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Light>, ParseError> {
+    let re = Regex::new(
+        r"^\s*position=<\s*(?P<pos_x>\S+),\s*(?P<pos_y>\S+)> velocity=<\s*(?P<vel_x>\S+),\s*(?P<vel_y>\S+)>\s*$",
+    )
+    .expect("light pattern is valid");
+
+    lines(Day10::DAY, input)
+        .map(|line| {
+            let captures = re
+                .captures(line.text)
+                .ok_or_else(|| line.invalid("expected `position=<x, y> velocity=<x, y>`"))?;
+            // Limiting the numbers to 32 bits keeps the lights from overflowing as they move.
+            let number = |name: &str| {
+                line.parse::<i32>(captures.name(name).unwrap().as_str(), "a number")
+                    .map(i64::from)
+            };
+
+            Ok(Light {
+                pos: Point::new(number("pos_x")?, number("pos_y")?),
+                vel: Vector::new(number("vel_x")?, number("vel_y")?),
+            })
+        })
+        .collect()
+}
+
+/// Moves the points until they line up, returning the second at which the message appears along
+/// with the rendered message.
+fn find_message(mut lights: Vec<Light>, config: &Config) -> Result<(u32, String), Box<dyn Error>> {
+    for second in 1..=config.max_seconds {
+        for light in &mut lights {
+            light.pos += light.vel;
+        }
+        if let Some(sky) = render_sky(&lights, config) {
+            debug!(second, "the lights formed a message");
+            return Ok((second, sky));
+        }
+    }
+    Err("The points never formed a message.".into())
+}
+
+/// Renders the lights, or returns `None` if they are spread over more than the configured message
+/// size and can't be forming a message yet.
+fn render_sky(lights: &[Light], config: &Config) -> Option<String> {
+    let bounds = Rect::bounding(lights.iter().map(|light| light.pos))?;
+    trace!(
+        width = bounds.width(),
+        height = bounds.height(),
+        "lights spread"
+    );
+
+    // This condition worked for my input, might need adjusted for other inputs?
+    if bounds.height() > config.message_height || bounds.width() > config.message_width {
+        return None;
+    }
+
+    // Shift the lights so the top left light is at the origin of the grid.
+    let offset = Point::ORIGIN - bounds.min;
+    let mut sky = Grid::new(bounds.width() as usize, bounds.height() as usize, '.');
+    for light in lights {
+        sky[light.pos + offset] = '#';
+    }
+
+    Some(sky.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_message, Config, Day10, Light};
+    use aoc_common::{Animate, Point, Solution, Vector};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>";
+
+    #[test]
+    fn message() {
+        let lights = Day10::parse(EXAMPLE).unwrap();
+        let (second, sky) = find_message(
+            lights,
+            &Config {
+                message_height: 8,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(second, 3);
+        assert_eq!(
+            sky,
+            "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###"
+        );
+    }
+
+    #[test]
+    fn animation() {
+        let lights = Day10::parse(EXAMPLE).unwrap();
+        let mut sky = Day10::animate(&lights, &Config::default()).unwrap();
+        assert!(sky.frame(80, 24).starts_with("Lights spread over 22x16\n"));
+        // Too spread out to fit, so each character covers a 2x2 patch of sky.
+        assert!(sky
+            .frame(11, 9)
+            .starts_with("Lights spread over 22x16, shrunk 2 times\n"));
+
+        for _ in 0..3 {
+            assert!(sky.step().unwrap());
+        }
+        assert_eq!(sky.frame(80, 24).lines().nth(4), Some("#####...#."));
+    }
+
+    #[test]
+    fn wide_skies() {
+        let lights = Day10::parse(
+            "position=<0, 0> velocity=<0, 0>\nposition=<2000000000, 0> velocity=<0, 0>",
+        )
+        .unwrap();
+        let config = Config {
+            max_seconds: 10,
+            ..Config::default()
+        };
+        assert!(find_message(lights, &config).is_err());
+        assert!(Day10::parse("position=<3000000000, 0> velocity=<0, 0>").is_err());
+    }
+
+    fn light() -> impl Strategy<Value = Light> {
+        (
+            -100_000i64..100_000,
+            -100_000i64..100_000,
+            -10i64..10,
+            -10i64..10,
+        )
+            .prop_map(|(x, y, dx, dy)| Light {
+                pos: Point::new(x, y),
+                vel: Vector::new(dx, dy),
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_lights(lights in prop::collection::vec(light(), 0..50)) {
+            let input = lights
+                .iter()
+                .map(|light| {
+                    format!(
+                        "position=<{:>7}, {:>7}> velocity=<{:>2}, {:>2}>\n",
+                        light.pos.x, light.pos.y, light.vel.x, light.vel.y
+                    )
+                })
+                .collect::<String>();
+            prop_assert_eq!(Day10::parse(&input).unwrap(), lights);
+        }
+
+        #[test]
+        fn rejects_malformed_numbers(bad in "[a-z]{1,4}|[0-9]+-|--[0-9]") {
+            let input = format!("position=< 1, {}> velocity=< 0, 0>", bad);
+            let err = Day10::parse(&input).unwrap_err();
+            prop_assert_eq!((err.column, err.text), (15, bad));
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{find_message, Config, Day10, Light};
+use aoc_common::{Image, Render, Rgb};
+use std::error::Error;
+
+const SKY: Rgb = Rgb::new(10, 14, 40);
+const LIGHT: Rgb = Rgb::new(255, 230, 120);
+
+impl Render for Day10 {
+    /// Draws the lights at the second they spell out the message.
+    fn render(lights: &Vec<Light>, config: &Config) -> Result<Image, Box<dyn Error>> {
+        let (second, _) = find_message(lights.clone(), config)?;
+        let positions = lights
+            .iter()
+            .map(|light| light.pos + light.vel * i64::from(second))
+            .collect::<Vec<_>>();
+        let (image, _) = Image::from_points(positions, SKY, |_| LIGHT)?;
+        Ok(image)
+    }
+}
@@ -0,0 +1,193 @@
+use aoc_common::error::single_line;
+use aoc_common::{Grid, NoConfig, ParseError, Point, Solution, Vector};
+use std::error::Error;
+use std::fmt;
+
+mod render;
+
+const GRID_WIDTH: usize = 300;
+const GRID_HEIGHT: usize = 300;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = FuelGrid;
+    type Config = NoConfig;
+    type PartOne = Point;
+    type PartTwo = Square;
+
+    fn parse(input: &str) -> Result<FuelGrid, ParseError> {
+        let line = single_line(Self::DAY, input, "a serial number")?;
+        let serial_number = line.parse(line.text.trim(), "a serial number")?;
+        Ok(FuelGrid::new(serial_number))
+    }
+
+    fn part_one(grid: &FuelGrid, _: &NoConfig) -> Result<Point, Box<dyn Error>> {
+        Ok(grid.find_best_square_with_size(3).corner)
+    }
+
+    fn part_two(grid: &FuelGrid, _: &NoConfig) -> Result<Square, Box<dyn Error>> {
+        Ok(grid.find_best_square())
+    }
+}
+
+#[derive(Debug)]
+pub struct FuelGrid {
+    sum_grid: Grid<i32>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Square {
+    corner: Point,
+    power_level: i32,
+    size: usize,
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.corner, self.size)
+    }
+}
+
+impl FuelGrid {
+    fn new(serial_number: i32) -> FuelGrid {
+        let mut sum_grid = Grid::new(GRID_WIDTH + 1, GRID_HEIGHT + 1, 0i32);
+
+        for y in 1..=GRID_HEIGHT {
+            for x in 1..=GRID_WIDTH {
+                let cell = if x > 1 && y > 1 {
+                    power_level(Point::from((x - 1, y - 1)), serial_number)
+                } else {
+                    0
+                };
+                sum_grid[(x, y)] =
+                    cell + sum_grid[(x - 1, y)] + sum_grid[(x, y - 1)] - sum_grid[(x - 1, y - 1)];
+            }
+        }
+
+        FuelGrid { sum_grid }
+    }
+
+    fn find_best_square(&self) -> Square {
+        (1..=GRID_WIDTH - 1)
+            .map(|size| self.find_best_square_with_size(size))
+            .max_by_key(|square| square.power_level)
+            .unwrap()
+    }
+
+    fn find_best_square_with_size(&self, size: usize) -> Square {
+        let mut squares = Vec::new();
+        for y in 1..=GRID_WIDTH {
+            for x in 1..=GRID_HEIGHT {
+                if let Some(square) = self.get_square(Point::from((x, y)), size) {
+                    squares.push(square);
+                }
+            }
+        }
+        squares
+            .into_iter()
+            .max_by_key(|square| square.power_level)
+            .unwrap_or_else(|| panic!("No best square for {}", size))
+    }
+
+    fn get_square(&self, corner: Point, size: usize) -> Option<Square> {
+        let offset = size as i64;
+        let bottom_right = corner + Vector::new(offset, offset);
+
+        if bottom_right.x > GRID_WIDTH as i64 || bottom_right.y > GRID_HEIGHT as i64 {
+            return None;
+        }
+
+        let top_right = corner + Vector::new(offset, 0);
+        let bottom_left = corner + Vector::new(0, offset);
+
+        let a = self.sum_grid[corner];
+        let b = self.sum_grid[top_right];
+        let c = self.sum_grid[bottom_left];
+        let d = self.sum_grid[bottom_right];
+
+        let power_level = d - b - c + a;
+
+        Some(Square {
+            corner,
+            power_level,
+            size,
+        })
+    }
+}
+
+fn power_level(coordinate: Point, serial_number: i32) -> i32 {
+    // Worked out in 64 bits so that large serial numbers can't overflow.
+    let rack_id = coordinate.x + 10;
+    let mut power_level = rack_id * coordinate.y;
+    power_level += i64::from(serial_number);
+    power_level *= rack_id;
+    power_level = (power_level % 1000) / 100;
+    power_level as i32 - 5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{power_level, Day11, FuelGrid};
+    use aoc_common::{Point, Solution};
+    use proptest::prelude::*;
+
+    #[test]
+    fn power_levels() {
+        assert_eq!(power_level(Point::new(3, 5), 8), 4);
+        assert_eq!(power_level(Point::new(122, 79), 57), -5);
+        assert_eq!(power_level(Point::new(217, 196), 39), 0);
+        assert_eq!(power_level(Point::new(101, 153), 71), 4);
+        assert_eq!(power_level(Point::new(300, 300), i32::MAX), 0);
+    }
+
+    #[test]
+    fn best_three_by_three() {
+        let square = FuelGrid::new(18).find_best_square_with_size(3);
+        assert_eq!(
+            (square.corner, square.power_level),
+            (Point::new(33, 45), 29)
+        );
+
+        let square = FuelGrid::new(42).find_best_square_with_size(3);
+        assert_eq!(
+            (square.corner, square.power_level),
+            (Point::new(21, 61), 30)
+        );
+    }
+
+    #[test]
+    fn best_of_any_size() {
+        let square = FuelGrid::new(18).find_best_square();
+        assert_eq!(
+            (square.to_string(), square.power_level),
+            ("90,269,16".into(), 113)
+        );
+
+        let square = FuelGrid::new(42).find_best_square();
+        assert_eq!(
+            (square.to_string(), square.power_level),
+            ("232,251,12".into(), 119)
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn parses_any_serial_number(serial in 0i32..100_000, x in 1i64..300, y in 1i64..300) {
+            let grid = Day11::parse(&format!("{}\n", serial)).unwrap();
+            let cell = grid.get_square(Point::new(x, y), 1).unwrap();
+            prop_assert_eq!(cell.power_level, power_level(Point::new(x, y), serial));
+        }
+
+        #[test]
+        fn rejects_malformed_serial_numbers(serial in "[a-z]{1,5}|[0-9]{1,3}[a-z.][0-9]{0,3}") {
+            let err = Day11::parse(&serial).unwrap_err();
+            prop_assert_eq!(err.text, serial);
+        }
+    }
+}
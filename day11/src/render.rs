@@ -0,0 +1,42 @@
+use crate::{Day11, FuelGrid, GRID_HEIGHT, GRID_WIDTH};
+use aoc_common::{Image, NoConfig, Point, Rect, Render, Rgb, Vector};
+use std::error::Error;
+
+const LOWEST: Rgb = Rgb::new(30, 60, 200);
+const HIGHEST: Rgb = Rgb::new(240, 60, 30);
+const BEST_THREE: Rgb = Rgb::WHITE;
+const BEST_ANY: Rgb = Rgb::new(255, 220, 0);
+
+impl Render for Day11 {
+    /// Draws the power level of every fuel cell, from blue for -5 to red for 4, with the best
+    /// 3x3 square outlined in white and the best square of any size in yellow.
+    fn render(grid: &FuelGrid, _: &NoConfig) -> Result<Image, Box<dyn Error>> {
+        let mut image = Image::new(GRID_WIDTH, GRID_HEIGHT, Rgb::BLACK)?;
+        for y in 1..=GRID_HEIGHT {
+            for x in 1..=GRID_WIDTH {
+                let corner = Point::from((x, y));
+                if let Some(cell) = grid.get_square(corner, 1) {
+                    let level = f64::from(cell.power_level + 5) / 9.0;
+                    image.set(to_pixel(corner), LOWEST.mix(HIGHEST, level));
+                }
+            }
+        }
+
+        for (square, colour) in &[
+            (grid.find_best_square(), BEST_ANY),
+            (grid.find_best_square_with_size(3), BEST_THREE),
+        ] {
+            let size = square.size as i64;
+            let outline = Rect::with_size(to_pixel(square.corner), size, size)
+                .expect("squares are at least one cell across");
+            image.outline(outline, *colour);
+        }
+
+        Ok(image)
+    }
+}
+
+/// Fuel cells are numbered from 1, but pixels from 0.
+fn to_pixel(cell: Point) -> Point {
+    cell - Vector::new(1, 1)
+}
@@ -0,0 +1,76 @@
+use crate::{get_score, next_generation, Config, Day12, Garden, Patterns, Pots};
+use aoc_common::{Animate, Simulation};
+use std::collections::VecDeque;
+use std::error::Error;
+
+/// How many past generations are kept around for drawing.
+const HISTORY: usize = 200;
+
+impl Animate for Day12 {
+    fn animate(garden: &Garden, _: &Config) -> Result<Box<dyn Simulation>, Box<dyn Error>> {
+        Ok(Box::new(Plants {
+            patterns: garden.patterns.clone(),
+            generations: vec![garden.initial_state.clone()].into(),
+        }))
+    }
+}
+
+struct Plants {
+    patterns: Patterns,
+    /// The latest generations, oldest first.
+    generations: VecDeque<Pots>,
+}
+
+impl Simulation for Plants {
+    fn step(&mut self) -> Result<bool, Box<dyn Error>> {
+        let latest = self
+            .generations
+            .back()
+            .expect("there is always a generation");
+        let next = next_generation(latest, &self.patterns);
+        if self.generations.len() == HISTORY {
+            self.generations.pop_front();
+        }
+        self.generations.push_back(next);
+        Ok(true)
+    }
+
+    /// Draws a row of pots for each generation, with the latest at the bottom.
+    fn frame(&self, width: usize, height: usize) -> String {
+        let latest = self
+            .generations
+            .back()
+            .expect("there is always a generation");
+        let shown = self
+            .generations
+            .iter()
+            .skip(
+                self.generations
+                    .len()
+                    .saturating_sub(height.saturating_sub(1)),
+            )
+            .collect::<Vec<_>>();
+
+        // The plants tend to drift along the row, so keep the leftmost shown plant in view.
+        let left = shown
+            .iter()
+            .filter_map(|pots| pots.iter().min())
+            .min()
+            .map_or(0, |id| id - 2);
+        let rows = shown.iter().map(|pots| {
+            (left..left + width as i64)
+                .map(|id| if pots.contains(&id) { '#' } else { '.' })
+                .collect::<String>()
+        });
+
+        let caption = format!(
+            "Pots {} onwards, the latest with plants add up to {}",
+            left,
+            get_score(latest)
+        );
+        std::iter::once(caption)
+            .chain(rows)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
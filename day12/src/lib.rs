@@ -0,0 +1,347 @@
+use aoc_common::error::lines;
+use aoc_common::{Line, ParseError, Solution};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
+use tracing::{debug, trace};
+
+mod animate;
+
+type PotId = i64;
+type Patterns = HashMap<String, bool>;
+type Pots = HashSet<PotId>;
+
+/// The initial pots with plants and the rules for how plants spread.
+#[derive(Debug, PartialEq)]
+pub struct Garden {
+    initial_state: Pots,
+    patterns: Patterns,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// How many generations to simulate for part one.
+    pub part_one_generations: usize,
+    /// How many generations to simulate for part two.
+    pub part_two_generations: usize,
+    /// How many generations to wait for the plants to start growing steadily, which is when the
+    /// rest can be worked out without simulating them, before giving up with an error.
+    pub simulation_limit: usize,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            part_one_generations: 20,
+            part_two_generations: 50_000_000_000,
+            simulation_limit: 10_000,
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Garden;
+    type Config = Config;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Garden, ParseError> {
+        let mut lines = lines(Self::DAY, input);
+        let initial_state = match lines.next() {
+            Some(line) => parse_initial_state(line)?,
+            None => {
+                return Err(ParseError::at_end(
+                    Self::DAY,
+                    input,
+                    "expected an initial state",
+                ))
+            }
+        };
+        let patterns = parse_patterns(lines)?;
+
+        if patterns.len() != 32 {
+            return Err(ParseError::at_end(
+                Self::DAY,
+                input,
+                format!("expected 32 patterns, found {}", patterns.len()),
+            ));
+        }
+
+        Ok(Garden {
+            initial_state,
+            patterns,
+        })
+    }
+
+    fn part_one(garden: &Garden, config: &Config) -> Result<i64, Box<dyn Error>> {
+        Ok(compute_final(
+            &garden.initial_state,
+            &garden.patterns,
+            config.part_one_generations,
+            config.simulation_limit,
+        )?)
+    }
+
+    fn part_two(garden: &Garden, config: &Config) -> Result<i64, Box<dyn Error>> {
+        Ok(compute_final(
+            &garden.initial_state,
+            &garden.patterns,
+            config.part_two_generations,
+            config.simulation_limit,
+        )?)
+    }
+}
+
+/// Works out the sum of the pots with plants after `generations`, or an error if the plants are
+/// still changing after `simulation_limit` generations or the sum gets too large.
+fn compute_final(
+    initial_state: &Pots,
+    patterns: &Patterns,
+    generations: usize,
+    simulation_limit: usize,
+) -> Result<i64, String> {
+    let mut current_generation: Pots = initial_state.clone();
+    let mut last_score = get_score(&current_generation);
+    let mut last_diff = 0;
+    let mut same_diff = 0;
+
+    for generation in 0..generations {
+        if generation == simulation_limit {
+            return Err(format!(
+                "The plants were still changing after {} generations.",
+                generation
+            ));
+        }
+
+        current_generation = next_generation(&current_generation, patterns);
+
+        let score = get_score(&current_generation);
+        let diff = score - last_score;
+        trace!(
+            generation = generation + 1,
+            plants = current_generation.len(),
+            score,
+            diff,
+            "grew"
+        );
+
+        if diff == last_diff {
+            same_diff += 1;
+        } else {
+            same_diff = 0;
+        }
+
+        if same_diff > 5 {
+            debug!(
+                generation = generation + 1,
+                diff, "the plants are growing steadily, extrapolating the rest"
+            );
+            // `score` is the sum after `generation + 1` generations.
+            return i64::try_from(generations - generation - 1)
+                .ok()
+                .and_then(|remaining| diff.checked_mul(remaining))
+                .and_then(|growth| score.checked_add(growth))
+                .ok_or_else(|| "The sum of the pots with plants is too large.".to_string());
+        }
+
+        last_score = score;
+        last_diff = diff;
+    }
+    Ok(last_score)
+}
+
+fn next_generation(pots: &Pots, patterns: &Patterns) -> Pots {
+    let min = pots.iter().min().cloned().unwrap_or(0) - 5;
+    let max = pots.iter().max().cloned().unwrap_or(0) + 5;
+    (min..=max)
+        .filter(|id| {
+            patterns
+                .get(&get_pattern(pots, *id))
+                .cloned()
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+fn get_score(pots: &Pots) -> i64 {
+    pots.iter().sum()
+}
+
+fn get_pattern(pots: &Pots, id: PotId) -> String {
+    let mut pattern = String::new();
+    for curr in id - 2..=id + 2 {
+        if pots.contains(&curr) {
+            pattern.push('#');
+        } else {
+            pattern.push('.');
+        }
+    }
+    pattern
+}
+
+fn parse_initial_state(line: Line) -> Result<Pots, ParseError> {
+    let re =
+        Regex::new(r"^\s*initial state: (?P<state>[.#]+)\s*$").expect("state pattern is valid");
+
+    let captures = re
+        .captures(line.text)
+        .ok_or_else(|| line.invalid("expected `initial state: ` followed by `.` and `#` pots"))?;
+
+    Ok(captures["state"]
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| *c == '#')
+        .map(|(id, _)| id as i64)
+        .collect())
+}
+
+fn parse_patterns<'a, I>(lines: I) -> Result<Patterns, ParseError>
+where
+    I: Iterator<Item = Line<'a>>,
+{
+    let re = Regex::new(r"^\s*(?P<pattern>[#.]{5}) => (?P<result>[#.])\s*$")
+        .expect("rule pattern is valid");
+
+    let mut patterns = Patterns::new();
+    for line in lines {
+        let captures = re
+            .captures(line.text)
+            .ok_or_else(|| line.invalid("expected a rule like `..#.# => #`"))?;
+        let pattern = captures.name("pattern").unwrap().as_str();
+        let result = captures["result"] == *"#";
+        if patterns.insert(pattern.into(), result).is_some() {
+            return Err(line.error(pattern, "expected each pattern to only have one rule"));
+        }
+    }
+
+    Ok(patterns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        compute_final, get_score, next_generation, parse_initial_state, parse_patterns, Day12,
+        Garden, Patterns,
+    };
+    use aoc_common::error::lines;
+    use aoc_common::Solution;
+    use proptest::prelude::*;
+
+    // The example only lists the rules that produce a plant, so it can't go through `Day12::parse`,
+    // which requires all 32.
+    const EXAMPLE: &str = "\
+initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #";
+
+    #[test]
+    fn plants_after_twenty_generations() {
+        let mut lines = lines(Day12::DAY, EXAMPLE);
+        let initial_state = parse_initial_state(lines.next().unwrap()).unwrap();
+        let patterns = parse_patterns(lines).unwrap();
+
+        assert_eq!(
+            get_score(&initial_state),
+            3 + 5 + 8 + 9 + 16 + 17 + 18 + 22 + 23 + 24
+        );
+        assert_eq!(compute_final(&initial_state, &patterns, 20, 100), Ok(325));
+    }
+
+    #[test]
+    fn extrapolating_matches_simulating() {
+        let garden = Day12::parse(Day12::INPUT).unwrap();
+        let mut pots = garden.initial_state.clone();
+        for generation in 1..=300 {
+            pots = next_generation(&pots, &garden.patterns);
+            if generation == 201 || generation == 300 {
+                assert_eq!(
+                    compute_final(&garden.initial_state, &garden.patterns, generation, 10_000),
+                    Ok(get_score(&pots)),
+                    "after {} generations",
+                    generation
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn plants_that_never_settle() {
+        // A plant appears wherever exactly one neighbour had one, which keeps changing forever.
+        let mut patterns = Patterns::new();
+        for pattern in 0..32 {
+            let plants = (0..5)
+                .rev()
+                .map(|bit| pattern & (1 << bit) != 0)
+                .collect::<Vec<_>>();
+            patterns.insert(pots(&plants), plants[1] != plants[3]);
+        }
+        let initial_state = [1].iter().cloned().collect();
+        assert!(compute_final(&initial_state, &patterns, 1_000_000, 300).is_err());
+    }
+
+    fn pots(plants: &[bool]) -> String {
+        plants
+            .iter()
+            .map(|&plant| if plant { '#' } else { '.' })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_garden(
+            initial_state in prop::collection::vec(any::<bool>(), 1..100),
+            results in prop::collection::vec(any::<bool>(), 32),
+        ) {
+            let patterns = (0..32)
+                .map(|pattern| {
+                    let plants = (0..5)
+                        .rev()
+                        .map(|bit| pattern & (1 << bit) != 0)
+                        .collect::<Vec<_>>();
+                    (pots(&plants), results[pattern])
+                })
+                .collect::<Patterns>();
+
+            let mut input = format!("initial state: {}\n\n", pots(&initial_state));
+            for (pattern, &result) in &patterns {
+                input += &format!("{} => {}\n", pattern, pots(&[result]));
+            }
+
+            let initial_state = initial_state
+                .iter()
+                .enumerate()
+                .filter(|(_, &plant)| plant)
+                .map(|(id, _)| id as i64)
+                .collect();
+            prop_assert_eq!(Day12::parse(&input).unwrap(), Garden { initial_state, patterns });
+        }
+
+        #[test]
+        fn rejects_malformed_rules(pattern in "[.#]{0,4}|[.#]{6,8}|[.#]{2}[a-z][.#]{2}") {
+            let input = format!("initial state: #..#\n\n{} => #", pattern);
+            let err = Day12::parse(&input).unwrap_err();
+            prop_assert_eq!((err.line, err.column), (3, 1));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Config, Crop, Day13};
+use aoc_common::{Animate, Direction, Grid, Point, Rect, Simulation};
+use std::error::Error;
+
+impl Animate for Day13 {
+    fn animate(crop: &Crop, _: &Config) -> Result<Box<dyn Simulation>, Box<dyn Error>> {
+        Ok(Box::new(Carts {
+            crop: crop.clone(),
+            crashes: Vec::new(),
+        }))
+    }
+}
+
+struct Carts {
+    crop: Crop,
+    crashes: Vec<Point>,
+}
+
+impl Simulation for Carts {
+    fn step(&mut self) -> Result<bool, Box<dyn Error>> {
+        if self.crop.carts.len() <= 1 {
+            return Ok(false);
+        }
+        let crashes = self.crop.tick()?;
+        self.crashes.extend(crashes);
+        Ok(true)
+    }
+
+    /// Draws as much of the tracks as fits, following the carts that are left.
+    fn frame(&self, width: usize, height: usize) -> String {
+        let tracks = &self.crop.tracks;
+        let (width, height) = (width.min(tracks.width()), height.saturating_sub(1));
+        let height = height.min(tracks.height());
+
+        let carts = Rect::bounding(self.crop.carts.keys().cloned());
+        let centre = carts.map_or(Point::ORIGIN, |carts| {
+            Point::new(
+                (carts.min.x + carts.max.x) / 2,
+                (carts.min.y + carts.max.y) / 2,
+            )
+        });
+        let start = |centre: i64, shown: usize, total: usize| {
+            (centre - shown as i64 / 2).clamp(0, (total - shown) as i64)
+        };
+        let corner = Point::new(
+            start(centre.x, width, tracks.width()),
+            start(centre.y, height, tracks.height()),
+        );
+
+        let mut view = Grid::from_fn(width, height, |x, y| {
+            tracks[Point::from((x, y)) + (corner - Point::ORIGIN)].to_char()
+        });
+        let mut draw = |position: Point, c: char| {
+            if let Some(cell) = view.at_mut(position + (Point::ORIGIN - corner)) {
+                *cell = c;
+            }
+        };
+        for &crash in &self.crashes {
+            draw(crash, 'X');
+        }
+        for cart in self.crop.carts.values() {
+            let c = match cart.direction {
+                Direction::Up => '^',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+                Direction::Right => '>',
+            };
+            draw(cart.position, c);
+        }
+
+        format!(
+            "{} carts left, {} crashed\n{}",
+            self.crop.carts.len(),
+            self.crashes.len() * 2,
+            view
+        )
+    }
+}
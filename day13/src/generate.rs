@@ -0,0 +1,74 @@
+use crate::Day13;
+use aoc_common::{Generator, Rng};
+
+impl Generator for Day13 {
+    /// Makes up `size` loops of track, with an odd number of carts spread between them so that
+    /// one can be left at the end.
+    ///
+    /// No two loops share a row or column for their sides, so wherever loops meet the tracks
+    /// cross at an intersection rather than running into each other.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let count = size.max(1);
+        let side = 3 * count + 2;
+        let mut map = vec![vec![' '; side]; side];
+
+        let mut xs = (0..side).collect::<Vec<_>>();
+        let mut ys = (0..side).collect::<Vec<_>>();
+        rng.shuffle(&mut xs);
+        rng.shuffle(&mut ys);
+        let loops = (0..count)
+            .map(|index| {
+                let (left, right) = ordered(xs[2 * index], xs[2 * index + 1]);
+                let (top, bottom) = ordered(ys[2 * index], ys[2 * index + 1]);
+                (left, right, top, bottom)
+            })
+            .collect::<Vec<_>>();
+
+        for &(left, right, top, bottom) in &loops {
+            for y in [top, bottom] {
+                for cell in &mut map[y][left + 1..right] {
+                    *cell = if *cell == '|' { '+' } else { '-' };
+                }
+            }
+            for row in &mut map[top + 1..bottom] {
+                for x in [left, right] {
+                    row[x] = if row[x] == '-' { '+' } else { '|' };
+                }
+            }
+            map[top][left] = '/';
+            map[bottom][right] = '/';
+            map[top][right] = '\\';
+            map[bottom][left] = '\\';
+        }
+
+        // Carts go on straight track, where their direction says which track is underneath.
+        let carts = count | 1;
+        let mut placed = 0;
+        for _ in 0..carts * 100 {
+            if placed == carts {
+                break;
+            }
+            let &(left, right, top, bottom) = rng.choose(&loops);
+            let (x, y) = if rng.chance(1, 2) {
+                (rng.range(left as i64..=right as i64) as usize, top)
+            } else {
+                (left, rng.range(top as i64..=bottom as i64) as usize)
+            };
+            let cart = match map[y][x] {
+                '-' => *rng.choose(&['<', '>']),
+                '|' => *rng.choose(&['^', 'v']),
+                _ => continue,
+            };
+            map[y][x] = cart;
+            placed += 1;
+        }
+
+        map.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+fn ordered(a: usize, b: usize) -> (usize, usize) {
+    (a.min(b), a.max(b))
+}
@@ -0,0 +1,391 @@
+use aoc_common::{Direction, Grid, ParseError, Point, Solution};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use tracing::debug;
+
+mod animate;
+mod generate;
+mod render;
+
+type Tracks = Grid<Track>;
+
+#[derive(Debug, Clone, Copy)]
+enum Track {
+    Empty,
+    Vertical,
+    Horizontal,
+    Intersection,
+    CornerForward,
+    CornerBackward,
+}
+
+impl Track {
+    fn from_char(c: char) -> Option<Track> {
+        match c {
+            ' ' => Some(Track::Empty),
+            '|' | '^' | 'v' => Some(Track::Vertical),
+            '-' | '>' | '<' => Some(Track::Horizontal),
+            '+' => Some(Track::Intersection),
+            '/' => Some(Track::CornerForward),
+            '\\' => Some(Track::CornerBackward),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Track::Empty => ' ',
+            Track::Vertical => '|',
+            Track::Horizontal => '-',
+            Track::Intersection => '+',
+            Track::CornerForward => '/',
+            Track::CornerBackward => '\\',
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Clone, Copy)]
+enum Movement {
+    Right,
+    Left,
+    Straight,
+}
+
+impl Movement {
+    fn next(self) -> Movement {
+        match self {
+            Movement::Left => Movement::Straight,
+            Movement::Straight => Movement::Right,
+            Movement::Right => Movement::Left,
+        }
+    }
+
+    fn new_direction(self, old_direction: Direction) -> Direction {
+        match self {
+            Movement::Straight => old_direction,
+            Movement::Right => old_direction.turn_right(),
+            Movement::Left => old_direction.turn_left(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Clone)]
+struct Cart {
+    position: Point,
+    direction: Direction,
+    next_decision: Movement,
+}
+
+impl Cart {
+    fn new(position: Point, direction: Direction) -> Cart {
+        Cart {
+            position,
+            direction,
+            next_decision: Movement::Left,
+        }
+    }
+
+    fn from_char(c: char, position: Point) -> Option<Cart> {
+        match c {
+            '>' => Some(Cart::new(position, Direction::Right)),
+            '<' => Some(Cart::new(position, Direction::Left)),
+            '^' => Some(Cart::new(position, Direction::Up)),
+            'v' => Some(Cart::new(position, Direction::Down)),
+            _ => None,
+        }
+    }
+
+    fn move_cart(&mut self) {
+        self.position += self.direction.offset();
+    }
+
+    fn update_direction(&mut self, track: Track) -> Result<(), String> {
+        let movement = match track {
+            Track::Vertical | Track::Horizontal => Movement::Straight,
+            Track::Intersection => {
+                let movement = self.next_decision;
+                self.next_decision = self.next_decision.next();
+                movement
+            }
+            Track::CornerBackward => match self.direction {
+                Direction::Up | Direction::Down => Movement::Left,
+                Direction::Left | Direction::Right => Movement::Right,
+            },
+            Track::CornerForward => match self.direction {
+                Direction::Up | Direction::Down => Movement::Right,
+                Direction::Left | Direction::Right => Movement::Left,
+            },
+            Track::Empty => {
+                return Err(format!("A cart ran off the track at {}.", self.position));
+            }
+        };
+        self.direction = movement.new_direction(self.direction);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Crop {
+    carts: BTreeMap<Point, Cart>,
+    tracks: Tracks,
+}
+
+impl Crop {
+    fn new(carts: BTreeMap<Point, Cart>, tracks: Tracks) -> Crop {
+        Crop { carts, tracks }
+    }
+
+    /// Returns the locations of any crashes in the order they happened, or an error if a cart
+    /// left the track.
+    fn tick(&mut self) -> Result<Vec<Point>, String> {
+        // Not very happy with this solution, but it works and I don't have enough time to clean it
+        // up :(
+        let mut new_carts = BTreeMap::new();
+        let mut crashes = Vec::new();
+        let mut carts_vec: Vec<_> = self.carts.values().rev().collect();
+
+        while let Some(cart) = carts_vec.pop() {
+            let mut new_cart = cart.clone();
+            new_cart.move_cart();
+            let track = self
+                .tracks
+                .at(new_cart.position)
+                .cloned()
+                .unwrap_or(Track::Empty);
+            new_cart.update_direction(track)?;
+
+            let new_position = new_cart.position;
+            if let Some(pos) = carts_vec
+                .iter()
+                .position(|cart| new_position == cart.position)
+            {
+                carts_vec.remove(pos);
+                crashes.push(new_position);
+            } else if new_carts.remove(&new_position).is_some() {
+                crashes.push(new_position);
+            } else {
+                new_carts.insert(new_position, new_cart);
+            }
+        }
+        self.carts = new_carts;
+
+        Ok(crashes)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// How many ticks to simulate before deciding the carts will never crash.
+    pub max_ticks: u32,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { max_ticks: 100_000 }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = Crop;
+    type Config = Config;
+    type PartOne = Point;
+    type PartTwo = Point;
+
+    fn parse(input: &str) -> Result<Crop, ParseError> {
+        let mut carts = BTreeMap::new();
+        let tracks = Grid::parse(input, Track::Empty, |x, y, c| {
+            if let Some(cart) = Cart::from_char(c, Point::from((x, y))) {
+                carts.insert(cart.position, cart);
+            }
+            Track::from_char(c).ok_or_else(|| {
+                ParseError::new(
+                    Self::DAY,
+                    y + 1,
+                    x + 1,
+                    &c.to_string(),
+                    "expected a track or a cart",
+                )
+            })
+        })?;
+
+        Ok(Crop::new(carts, tracks))
+    }
+
+    /// Finds the location of the first crash.
+    fn part_one(crop: &Crop, config: &Config) -> Result<Point, Box<dyn Error>> {
+        let mut crop = crop.clone();
+        for tick in 1..=config.max_ticks {
+            if let Some(crash) = crop.tick()?.first() {
+                debug!(tick, position = %crash, "first crash");
+                return Ok(*crash);
+            }
+        }
+        Err(format!("No carts crashed within {} ticks.", config.max_ticks).into())
+    }
+
+    /// Finds the location of the last cart left after all the others have crashed.
+    fn part_two(crop: &Crop, config: &Config) -> Result<Point, Box<dyn Error>> {
+        let mut crop = crop.clone();
+        let mut ticks = 0;
+        while crop.carts.len() > 1 {
+            if ticks == config.max_ticks {
+                return Err(format!(
+                    "{} carts were still left after {} ticks.",
+                    crop.carts.len(),
+                    ticks
+                )
+                .into());
+            }
+            ticks += 1;
+            for crash in crop.tick()? {
+                debug!(
+                    tick = ticks,
+                    position = %crash,
+                    carts_left = crop.carts.len(),
+                    "carts crashed"
+                );
+            }
+        }
+
+        let position = crop.carts.keys().next().ok_or("Every cart crashed.")?;
+        Ok(*position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, Day13};
+    use aoc_common::{Animate, Point, Solution};
+    use proptest::prelude::*;
+
+    #[test]
+    fn first_crash() {
+        let crop = Day13::parse(
+            r"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/   ",
+        )
+        .unwrap();
+        assert_eq!(
+            Day13::part_one(&crop, &Config::default()).unwrap(),
+            Point::new(7, 3)
+        );
+    }
+
+    #[test]
+    fn last_cart() {
+        let crop = Day13::parse(
+            r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/",
+        )
+        .unwrap();
+        assert_eq!(
+            Day13::part_two(&crop, &Config::default()).unwrap(),
+            Point::new(6, 4)
+        );
+    }
+
+    #[test]
+    fn animation() {
+        let crop = Day13::parse(
+            r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/",
+        )
+        .unwrap();
+        let mut carts = Day13::animate(&crop, &Config::default()).unwrap();
+        assert!(carts.step().unwrap());
+        assert_eq!(
+            carts.frame(80, 24),
+            "3 carts left, 6 crashed
+/-X-\\  
+|   |  
+| v-+-\\
+| | | |
+\\-X-/ X
+  |   |
+  ^---^"
+        );
+
+        while carts.step().unwrap() {}
+        let frame = carts.frame(3, 3);
+        assert!(frame.starts_with("1 carts left, 8 crashed\n"));
+        assert_eq!(frame.lines().count(), 3);
+    }
+
+    #[test]
+    fn carts_that_leave_the_track() {
+        let crop = Day13::parse("->- ").unwrap();
+        assert_eq!(
+            Day13::part_one(&crop, &Config::default())
+                .unwrap_err()
+                .to_string(),
+            "A cart ran off the track at 3,0."
+        );
+        let crop = Day13::parse("-<").unwrap();
+        assert!(Day13::part_one(&crop, &Config::default()).is_err());
+    }
+
+    #[test]
+    fn carts_that_never_crash() {
+        let config = Config { max_ticks: 100 };
+        let crop = Day13::parse(
+            r"/>\/>\
+\-/\-/",
+        )
+        .unwrap();
+        assert!(Day13::part_one(&crop, &config).is_err());
+        assert!(Day13::part_two(&crop, &config).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_track_characters(
+            rows in prop::collection::vec(r"[ |\-+/\\^v<>]{1,20}", 1..20),
+        ) {
+            let crop = Day13::parse(&rows.join("\n")).unwrap();
+            let carts = rows
+                .iter()
+                .flat_map(|row| row.chars())
+                .filter(|c| "^v<>".contains(*c))
+                .count();
+            prop_assert_eq!(crop.carts.len(), carts);
+            prop_assert_eq!(crop.tracks.height(), rows.len());
+        }
+
+        #[test]
+        fn rejects_other_characters(
+            rows in prop::collection::vec(r"[ |\-+/\\]{1,20}", 1..20),
+            row in any::<prop::sample::Index>(),
+            column in any::<prop::sample::Index>(),
+            bad in "[a-uw-z#.=]",
+        ) {
+            let mut rows = rows;
+            let y = row.index(rows.len());
+            let x = column.index(rows[y].len());
+            rows[y].replace_range(x..x + 1, &bad);
+
+            let err = Day13::parse(&rows.join("\n")).unwrap_err();
+            prop_assert_eq!((err.line, err.column, err.text), (y + 1, x + 1, bad));
+        }
+    }
+}
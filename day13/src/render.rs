@@ -0,0 +1,27 @@
+use crate::{Config, Crop, Day13, Track};
+use aoc_common::{Image, Render, Rgb, Solution};
+use std::error::Error;
+
+const GROUND: Rgb = Rgb::new(20, 24, 20);
+const TRACK: Rgb = Rgb::new(130, 130, 130);
+const INTERSECTION: Rgb = Rgb::new(200, 200, 200);
+const CART: Rgb = Rgb::new(255, 210, 0);
+const CRASH: Rgb = Rgb::new(255, 40, 40);
+
+impl Render for Day13 {
+    /// Draws the tracks with the carts where they start, and the first crash in red.
+    fn render(crop: &Crop, config: &Config) -> Result<Image, Box<dyn Error>> {
+        let mut image = Image::from_grid(&crop.tracks, |track| match track {
+            Track::Empty => GROUND,
+            Track::Intersection => INTERSECTION,
+            _ => TRACK,
+        })?;
+        for position in crop.carts.keys() {
+            image.set(*position, CART);
+        }
+        if let Ok(crash) = Day13::part_one(crop, config) {
+            image.set(crash, CRASH);
+        }
+        Ok(image)
+    }
+}
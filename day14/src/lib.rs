@@ -0,0 +1,201 @@
+use aoc_common::error::single_line;
+use aoc_common::{ParseError, Solution};
+use serde::Deserialize;
+use std::error::Error;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// How many recipes the elves can make before giving up, since every recipe is kept in memory.
+    pub max_recipes: usize,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            max_recipes: 100_000_000,
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Puzzle = String;
+    type Config = Config;
+    type PartOne = String;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let line = single_line(Self::DAY, input, "a recipe score sequence")?;
+        let digits = line.text.trim();
+        match digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            Some((index, c)) => {
+                Err(line.error(&digits[index..index + c.len_utf8()], "expected a digit"))
+            }
+            None => Ok(digits.to_owned()),
+        }
+    }
+
+    fn part_one(input: &String, config: &Config) -> Result<String, Box<dyn Error>> {
+        let recipe_count = input.parse::<usize>()?;
+        if recipe_count.saturating_add(10) > config.max_recipes {
+            return Err(format!(
+                "Making {} recipes is more than the limit of {}.",
+                input, config.max_recipes
+            )
+            .into());
+        }
+        let recipes = Recipes::new().skip(recipe_count).take(10);
+        Ok(to_output(recipes))
+    }
+
+    fn part_two(input: &String, config: &Config) -> Result<usize, Box<dyn Error>> {
+        find_pattern(input, config.max_recipes)
+    }
+}
+
+/// Counts the recipes made before the scores in `input` first appear.
+fn find_pattern(input: &str, max_recipes: usize) -> Result<usize, Box<dyn Error>> {
+    if input.is_empty() {
+        return Err("There is no pattern to look for.".into());
+    }
+    let pattern = input.bytes().map(|digit| digit - b'0').collect::<Vec<_>>();
+
+    let mut recipes = Recipes::new();
+    while recipes.current_index < max_recipes {
+        recipes.next();
+        let made = &recipes.recipes[..recipes.current_index];
+        if made.ends_with(&pattern) {
+            return Ok(made.len() - pattern.len());
+        }
+    }
+
+    Err(format!(
+        "{} didn't appear in the first {} recipes.",
+        input, max_recipes
+    )
+    .into())
+}
+
+type RecipeId = usize;
+type Score = u8;
+
+struct Recipes {
+    elves: [RecipeId; 2],
+    recipes: Vec<Score>,
+    current_index: usize,
+}
+
+impl Recipes {
+    fn new() -> Recipes {
+        Recipes {
+            recipes: vec![3, 7],
+            elves: [0, 1],
+            current_index: 0,
+        }
+    }
+}
+
+impl Iterator for Recipes {
+    type Item = Score;
+
+    fn next(&mut self) -> Option<Score> {
+        if let Some(score) = self.recipes.get(self.current_index) {
+            self.current_index += 1;
+            return Some(*score);
+        }
+
+        let sum = self.recipes[self.elves[0]] + self.recipes[self.elves[1]];
+        let tens = sum / 10;
+        let ones = sum % 10;
+
+        if tens > 0 {
+            self.recipes.push(tens);
+        }
+        self.recipes.push(ones);
+
+        for elf in &mut self.elves {
+            *elf = (*elf + usize::from(self.recipes[*elf]) + 1) % self.recipes.len();
+        }
+
+        let recipe = self.recipes.get(self.current_index).cloned();
+        self.current_index += 1;
+        recipe
+    }
+}
+
+fn to_output<I: Iterator<Item = Score>>(scores: I) -> String {
+    scores.map(|score| score.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_pattern, to_output, Config, Day14, Recipes};
+    use aoc_common::Solution;
+    use proptest::prelude::*;
+
+    #[test]
+    fn after_9() {
+        let recipes = Recipes::new().skip(9).take(10);
+
+        assert_eq!(to_output(recipes), "5158916779");
+    }
+
+    #[test]
+    fn after_5() {
+        let recipes = Recipes::new().skip(5).take(10);
+
+        assert_eq!(to_output(recipes), "0124515891");
+    }
+
+    #[test]
+    fn after_18() {
+        let recipes = Recipes::new().skip(18).take(10);
+
+        assert_eq!(to_output(recipes), "9251071085");
+    }
+
+    #[test]
+    fn after_2018() {
+        let recipes = Recipes::new().skip(2018).take(10);
+
+        assert_eq!(to_output(recipes), "5941429882");
+    }
+
+    #[test]
+    fn recipes_before_pattern() {
+        assert_eq!(find_pattern("51589", 10_000).unwrap(), 9);
+        assert_eq!(find_pattern("01245", 10_000).unwrap(), 5);
+        assert_eq!(find_pattern("92510", 10_000).unwrap(), 18);
+        assert_eq!(find_pattern("59414", 10_000).unwrap(), 2018);
+    }
+
+    #[test]
+    fn limited_recipes() {
+        let config = Config { max_recipes: 1000 };
+        assert!(Day14::part_one(&"991".to_string(), &config).is_err());
+        assert!(Day14::part_two(&"59414".to_string(), &config).is_err());
+        assert!(Day14::part_two(&"1".repeat(40), &config).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parses_any_digits(digits in "[0-9]{1,10}") {
+            prop_assert_eq!(Day14::parse(&format!("{}\n", digits)).unwrap(), digits);
+        }
+
+        #[test]
+        fn rejects_other_characters(
+            before in "[0-9]{0,5}",
+            bad in "[a-z+.-]",
+            after in "[0-9]{0,5}",
+        ) {
+            let err = Day14::parse(&format!("{}{}{}", before, bad, after)).unwrap_err();
+            prop_assert_eq!((err.column, err.text), (before.len() + 1, bad));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day01::Day01;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| solve::<Day01>(input, &Default::default()));
@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day02::Day02;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| solve::<Day02>(input, &Default::default()));
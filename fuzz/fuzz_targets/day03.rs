@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day03::Day03;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| solve::<Day03>(input, &Default::default()));
@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day05::Day05;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| solve::<Day05>(input, &Default::default()));
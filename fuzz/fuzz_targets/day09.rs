@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day09::{Config, Day09};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| {
+    let config = Config {
+        max_marbles: 100_000,
+        ..Default::default()
+    };
+    solve::<Day09>(input, &config);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day10::{Config, Day10};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| {
+    let config = Config {
+        max_seconds: 1000,
+        ..Default::default()
+    };
+    solve::<Day10>(input, &config);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day12::{Config, Day12};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| {
+    let config = Config {
+        simulation_limit: 1000,
+        ..Default::default()
+    };
+    solve::<Day12>(input, &config);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day13::{Config, Day13};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| {
+    let config = Config { max_ticks: 1000 };
+    solve::<Day13>(input, &config);
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_fuzz::solve;
+use day14::{Config, Day14};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| {
+    let config = Config {
+        max_recipes: 100_000,
+    };
+    solve::<Day14>(input, &config);
+});
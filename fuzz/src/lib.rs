@@ -0,0 +1,20 @@
+//! Shared code for the fuzz targets, which each run one day's whole pipeline on arbitrary input.
+//!
+//! Every target is expected to return without panicking, overflowing or hanging, whatever the
+//! input. Days with simulations that run for as long as their config allows are given smaller
+//! limits than the puzzle needs, so that each run stays quick.
+
+use aoc_common::Solution;
+use std::str;
+
+/// Parses `input` and solves both parts, ignoring any errors.
+pub fn solve<S: Solution>(input: &[u8], config: &S::Config) {
+    let input = match str::from_utf8(input) {
+        Ok(input) => input,
+        Err(_) => return,
+    };
+    if let Ok(puzzle) = S::parse(input) {
+        let _ = S::part_one(&puzzle, config);
+        let _ = S::part_two(&puzzle, config);
+    }
+}